@@ -0,0 +1,103 @@
+use num::Zero;
+
+use crate::{
+    derivative::gradient, order::Order, polynomial::Polynomial, ring::Ring, term::Term,
+    variable::Variable,
+};
+
+/// Generators of the ideal of the singular locus of the hypersurface `f = 0`,
+/// `f` and its partial derivatives with respect to `variables`.
+pub fn singular_locus<R: Ring, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    variables: &[V],
+) -> Vec<Polynomial<R, V, O>> {
+    [f.clone()]
+        .into_iter()
+        .chain(gradient(f, variables))
+        .filter(|g| !g.is_zero())
+        .collect()
+}
+
+/// Generators of the Lagrange ideal for the critical points of `objective` on
+/// the variety of `constraints`, one multiplier variable per constraint.
+///
+/// The ideal contains the constraints and, for every variable `x`, the
+/// polynomial `d objective/dx - sum(multipliers[i] * d constraints[i]/dx)`.
+/// The multipliers can be projected out with
+/// [`eliminate`](crate::polynomial::eliminate).
+pub fn lagrange_ideal<R: Ring, V: Variable, O: Order>(
+    objective: &Polynomial<R, V, O>,
+    constraints: &[Polynomial<R, V, O>],
+    variables: &[V],
+    multipliers: &[V],
+) -> Vec<Polynomial<R, V, O>> {
+    assert_eq!(
+        constraints.len(),
+        multipliers.len(),
+        "One multiplier is needed for each constraint"
+    );
+
+    let gradients: Vec<Vec<Polynomial<R, V, O>>> =
+        constraints.iter().map(|g| gradient(g, variables)).collect();
+
+    gradient(objective, variables)
+        .into_iter()
+        .enumerate()
+        .map(|(j, df)| {
+            gradients
+                .iter()
+                .zip(multipliers.iter())
+                .fold(df, |acc, (dg, &lambda)| {
+                    acc - &dg[j] * Term::from_iter([(lambda, 1)])
+                })
+        })
+        .chain(constraints.iter().cloned())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        order::Lex,
+        polynomial::{buchberger, eliminate, reduced_basis},
+        ring::Rational,
+        var,
+        variable::Var,
+    };
+
+    #[test]
+    fn test_singular_locus() {
+        // the cusp y^2 = x^3 is singular at the origin only
+        let f: Polynomial<Rational, Var, Lex> = Polynomial::from_str("y^2+-x^3").unwrap();
+        let ideal = singular_locus(&f, &[var!(x), var!(y)]);
+
+        assert_eq!(ideal.len(), 3);
+        assert_eq!(
+            reduced_basis(&buchberger(&ideal)),
+            vec![
+                Polynomial::from_str("x^2").unwrap(),
+                Polynomial::from_str("y").unwrap()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lagrange_ideal() {
+        // extrema of x + y on the circle x^2 + y^2 = 2, at x = y = 1 and -1
+        let objective: Polynomial<Rational, Var, Lex> = Polynomial::from_str("x+y").unwrap();
+        let circle: Polynomial<Rational, Var, Lex> = Polynomial::from_str("x^2+y^2+-2").unwrap();
+        let lambda = Var(11);
+
+        let ideal = lagrange_ideal(&objective, &[circle], &[var!(x), var!(y)], &[lambda]);
+        assert_eq!(
+            eliminate(&ideal, &[lambda]),
+            vec![
+                Polynomial::from_str("x+-y").unwrap(),
+                Polynomial::from_str("y^2+-1").unwrap()
+            ]
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use itertools::{EitherOrBoth, Itertools};
+use num::{FromPrimitive, Zero};
+
+/// Arbitrary precision coefficients of dense polynomials, such as `BigInt`
+/// and `BigRational`, which are not `Copy` and so not a [`Ring`].
+///
+/// [`Ring`]: crate::ring::Ring
+pub(crate) trait Coefficient:
+    Clone
+    + PartialEq
+    + Zero
+    + FromPrimitive
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+}
+
+impl<T> Coefficient for T where
+    T: Clone
+        + PartialEq
+        + Zero
+        + FromPrimitive
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Neg<Output = T>
+{
+}
+
+/// Dense polynomial with coefficients from the constant term up and no
+/// trailing zeros.
+pub(crate) type Dense<T> = Vec<T>;
+
+pub(crate) fn trim<T: Coefficient>(mut f: Dense<T>) -> Dense<T> {
+    while f.last().is_some_and(|c| c.is_zero()) {
+        f.pop();
+    }
+    f
+}
+
+pub(crate) fn add<T: Coefficient>(f: &[T], g: &[T]) -> Dense<T> {
+    trim(
+        f.iter()
+            .zip_longest(g.iter())
+            .map(|pair| match pair {
+                EitherOrBoth::Both(a, b) => a.clone() + b.clone(),
+                EitherOrBoth::Left(c) | EitherOrBoth::Right(c) => c.clone(),
+            })
+            .collect(),
+    )
+}
+
+pub(crate) fn sub<T: Coefficient>(f: &[T], g: &[T]) -> Dense<T> {
+    add(f, &g.iter().map(|c| -c.clone()).collect::<Dense<T>>())
+}
+
+pub(crate) fn mul<T: Coefficient>(f: &[T], g: &[T]) -> Dense<T> {
+    if f.is_empty() || g.is_empty() {
+        return vec![];
+    }
+    let mut result = vec![T::zero(); f.len() + g.len() - 1];
+    for (i, a) in f.iter().enumerate() {
+        for (j, b) in g.iter().enumerate() {
+            result[i + j] = result[i + j].clone() + a.clone() * b.clone();
+        }
+    }
+    trim(result)
+}
+
+pub(crate) fn eval<T: Coefficient>(f: &[T], x: &T) -> T {
+    f.iter()
+        .rev()
+        .fold(T::zero(), |acc, c| acc * x.clone() + c.clone())
+}
+
+pub(crate) fn derivative<T: Coefficient>(f: &[T]) -> Dense<T> {
+    trim(
+        f.iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| c.clone() * T::from_usize(i).unwrap())
+            .collect(),
+    )
+}
+
+/// Remainder of `lc(g)^(deg(f) - deg(g) + 1) * f` by `g`, without divisions.
+pub(crate) fn pseudo_remainder<T: Coefficient>(f: &[T], g: &[T]) -> Dense<T> {
+    let mut r = f.to_vec();
+    let lead = g.last().unwrap();
+    while r.len() >= g.len() {
+        let shift = r.len() - g.len();
+        let factor = r.pop().unwrap();
+        for c in r.iter_mut() {
+            *c = c.clone() * lead.clone();
+        }
+        for (c, d) in r[shift..].iter_mut().zip(g.iter()) {
+            *c = c.clone() - factor.clone() * d.clone();
+        }
+        r = trim(r);
+    }
+    r
+}
+
+/// Euclidean division over a field, returns the quotient and the remainder.
+pub(crate) fn div_rem<T>(f: &[T], g: &[T]) -> (Dense<T>, Dense<T>)
+where
+    T: Coefficient + Div<Output = T>,
+{
+    let d = g.len() - 1;
+    let mut remainder = f.to_vec();
+    let mut quotient = vec![T::zero(); f.len().saturating_sub(d)];
+    for i in (d..remainder.len()).rev() {
+        let c = remainder[i].clone() / g[d].clone();
+        if c.is_zero() {
+            continue;
+        }
+        for (j, b) in g.iter().enumerate() {
+            remainder[i - d + j] = remainder[i - d + j].clone() - c.clone() * b.clone();
+        }
+        quotient[i - d] = c;
+    }
+    remainder.truncate(d);
+    (trim(quotient), trim(remainder))
+}
+
+/// Greatest common divisor over a field, up to a constant factor.
+pub(crate) fn gcd<T>(f: &[T], g: &[T]) -> Dense<T>
+where
+    T: Coefficient + Div<Output = T>,
+{
+    let (mut a, mut b) = (f.to_vec(), g.to_vec());
+    while !b.is_empty() {
+        let (_, r) = div_rem(&a, &b);
+        (a, b) = (b, r);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use num::{BigInt, BigRational};
+
+    use super::*;
+
+    fn integers(coeffs: &[i64]) -> Dense<BigInt> {
+        coeffs.iter().map(|&c| BigInt::from(c)).collect()
+    }
+
+    #[test]
+    fn test_dense_integers() {
+        // (x + 1)(2x - 3) = 2x^2 - x - 3
+        let f = mul(&integers(&[1, 1]), &integers(&[-3, 2]));
+        assert_eq!(f, integers(&[-3, -1, 2]));
+        assert_eq!(derivative(&f), integers(&[-1, 4]));
+        assert_eq!(eval(&f, &BigInt::from(2)), BigInt::from(3));
+        assert_eq!(sub(&f, &f), integers(&[]));
+
+        // 2^2 (x^2 + 1) = (2x + 1)(2x - 1) + 5
+        let r = pseudo_remainder(&integers(&[1, 0, 1]), &integers(&[1, 2]));
+        assert_eq!(r, integers(&[5]));
+    }
+
+    #[test]
+    fn test_dense_rationals() {
+        let rationals = |coeffs: &[i64]| -> Dense<BigRational> {
+            coeffs
+                .iter()
+                .map(|&c| BigRational::from_integer(c.into()))
+                .collect()
+        };
+        // x^2 - 1 and x^2 + 2x + 1 share the factor x + 1
+        let (f, g) = (rationals(&[-1, 0, 1]), rationals(&[1, 2, 1]));
+        let (q, r) = div_rem(&f, &g);
+        assert_eq!((q, r), (rationals(&[1]), rationals(&[-2, -2])));
+        assert_eq!(gcd(&f, &g), rationals(&[-2, -2]));
+    }
+}
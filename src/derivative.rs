@@ -0,0 +1,96 @@
+use crate::{
+    monomial::Monomial,
+    order::Order,
+    polynomial::Polynomial,
+    ring::{mul_by_integer, Ring},
+    term::Term,
+    variable::Variable,
+};
+
+impl<V: Variable> Term<V> {
+    /// Partial derivative with respect to `var`, as the integer factor and
+    /// the remaining term, the factor being zero when `var` does not appear.
+    pub fn derivative(&self, var: V) -> (usize, Term<V>) {
+        match self.exps.iter().find(|&&(v, _)| v == var) {
+            Some(&(_, exp)) => (
+                exp,
+                self.exps
+                    .iter()
+                    .map(|&(v, e)| if v == var { (v, e - 1) } else { (v, e) })
+                    .collect(),
+            ),
+            None => (0, Term::default()),
+        }
+    }
+}
+
+impl<R: Ring, V: Variable> Monomial<R, V> {
+    /// Partial derivative with respect to `var`. The exponent multiplies the
+    /// coefficient as a sum in the ring, so it vanishes in characteristic `p`
+    /// when `p` divides the exponent.
+    pub fn derivative(&self, var: V) -> Monomial<R, V> {
+        let (factor, term) = self.term.derivative(var);
+        Monomial {
+            coeff: mul_by_integer(self.coeff, factor),
+            term,
+        }
+    }
+}
+
+impl<R: Ring, V: Variable, O: Order> Polynomial<R, V, O> {
+    /// Partial derivative with respect to `var`.
+    pub fn derivative(&self, var: V) -> Polynomial<R, V, O> {
+        self.iter().map(|m| m.derivative(var)).collect()
+    }
+}
+
+/// Partial derivatives of `f` with respect to each of `variables`.
+pub fn gradient<R: Ring, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    variables: &[V],
+) -> Vec<Polynomial<R, V, O>> {
+    variables.iter().map(|&var| f.derivative(var)).collect()
+}
+
+/// Jacobian matrix of `polys`, the row `i` is the gradient of `polys[i]`.
+pub fn jacobian<R: Ring, V: Variable, O: Order>(
+    polys: &[Polynomial<R, V, O>],
+    variables: &[V],
+) -> Vec<Vec<Polynomial<R, V, O>>> {
+    polys.iter().map(|f| gradient(f, variables)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use num::Zero;
+
+    use super::*;
+    use crate::{ring::Z2, var, variable::Var};
+
+    #[test]
+    fn test_derivative() {
+        let f: Polynomial<i32, Var> = Polynomial::from_str("x^3y^2+-4xz+7").unwrap();
+
+        assert_eq!(
+            f.derivative(var!(x)),
+            Polynomial::from_str("3x^2y^2+-4z").unwrap()
+        );
+        assert_eq!(
+            jacobian(std::slice::from_ref(&f), &[var!(y), var!(z)]),
+            vec![vec![
+                Polynomial::from_str("2x^3y").unwrap(),
+                Polynomial::from_str("-4x").unwrap()
+            ]]
+        );
+        assert!(f.derivative(Var(0)).is_zero());
+
+        // 2x = 0 and 3x^2 = x^2 in characteristic 2
+        let g: Polynomial<Z2, Var> = Polynomial::from_str("x^3+x^2+y").unwrap();
+        assert_eq!(
+            gradient(&g, &[var!(x)]),
+            vec![Polynomial::from_str("x^2").unwrap()]
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use num::{One, Zero};
+
+use crate::{
+    monomial::Monomial,
+    order::Order,
+    polynomial::Polynomial,
+    ring::{pow_ring, Ring},
+    term::Term,
+    variable::Variable,
+};
+
+/// Powers of `f` computed once and reused, `f^0` up to the largest asked.
+struct Powers<'a, R: Ring, V: Variable, O: Order> {
+    base: &'a Polynomial<R, V, O>,
+    cache: Vec<Polynomial<R, V, O>>,
+}
+
+impl<'a, R: Ring, V: Variable, O: Order> Powers<'a, R, V, O> {
+    fn new(base: &'a Polynomial<R, V, O>) -> Self {
+        Self {
+            base,
+            cache: vec![Polynomial::one()],
+        }
+    }
+
+    fn get(&mut self, exp: usize) -> &Polynomial<R, V, O> {
+        while self.cache.len() <= exp {
+            let next = self.cache.last().unwrap() * self.base;
+            self.cache.push(next);
+        }
+        &self.cache[exp]
+    }
+}
+
+impl<R: Ring, V: Variable, O: Order> Polynomial<R, V, O> {
+    /// Value at the point mapping every variable `var` to `point(var)`.
+    pub fn eval(&self, point: &impl Fn(V) -> R) -> R {
+        self.iter().fold(R::zero(), |acc, m| {
+            acc + m.term.exps.iter().fold(m.coeff, |value, &(var, exp)| {
+                value * pow_ring(point(var), exp)
+            })
+        })
+    }
+
+    /// Substitute the value `point(var)` for the variables where it is
+    /// defined, giving a polynomial in the remaining variables.
+    pub fn partial_eval(&self, point: &impl Fn(V) -> Option<R>) -> Self {
+        self.iter()
+            .map(|m| {
+                let mut coeff = m.coeff;
+                let mut remaining: Vec<(V, usize)> = Default::default();
+                for &(var, exp) in m.term.exps.iter() {
+                    match point(var) {
+                        Some(x) => coeff = coeff * pow_ring(x, exp),
+                        None => remaining.push((var, exp)),
+                    }
+                }
+                Monomial {
+                    coeff,
+                    term: remaining.into_iter().collect(),
+                }
+            })
+            .collect()
+    }
+
+    /// Composition replacing `var` with the polynomial `g`.
+    pub fn substitute(&self, var: V, g: &Polynomial<R, V, O>) -> Self {
+        let mut powers = Powers::new(g);
+        self.iter().fold(Polynomial::zero(), |acc, m| {
+            let exp = m
+                .term
+                .exps
+                .iter()
+                .find(|&&(v, _)| v == var)
+                .map_or(0, |&(_, exp)| exp);
+            let rest: Term<V> = m.term.exps.iter().filter(|&&(v, _)| v != var).collect();
+            acc + powers.get(exp) * (rest * m.coeff)
+        })
+    }
+
+    /// Image under the ring homomorphism sending coefficients through
+    /// `coeff_map` and every variable `var` to the polynomial `var_map(var)`,
+    /// possibly over another ring, variables and order.
+    pub fn map_homomorphism<S: Ring, W: Variable, P: Order>(
+        &self,
+        coeff_map: impl Fn(R) -> S,
+        var_map: impl Fn(V) -> Polynomial<S, W, P>,
+    ) -> Polynomial<S, W, P> {
+        let mut images: Vec<(V, Polynomial<S, W, P>)> = Default::default();
+        let mut result: Polynomial<S, W, P> = Polynomial::zero();
+
+        for m in self.iter() {
+            let mut image: Polynomial<S, W, P> =
+                [Term::default() * coeff_map(m.coeff)].into_iter().collect();
+            for &(var, exp) in m.term.exps.iter() {
+                let var_image = match images.iter().find(|(v, _)| *v == var) {
+                    Some((_, image)) => image.clone(),
+                    None => {
+                        let image = var_map(var);
+                        images.push((var, image.clone()));
+                        image
+                    }
+                };
+                image = (0..exp).fold(image, |acc, _| acc * &var_image);
+            }
+            result = result + image;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{order::GradLex, ring::FiniteField, static_prime_field, var, variable::Var};
+
+    static_prime_field!(Z7(7:u32));
+
+    #[test]
+    fn test_eval_and_substitute() {
+        let f: Polynomial<i32, Var> = Polynomial::from_str("x^2y+-3xz+2").unwrap();
+
+        let value = f.eval(&|v| match v {
+            Var(23) => 2,
+            Var(24) => 5,
+            _ => -1,
+        });
+        assert_eq!(value, 28);
+
+        let partial = f.partial_eval(&|v| (v == var!(x)).then_some(2));
+        assert_eq!(partial, Polynomial::from_str("4y+-6z+2").unwrap());
+
+        let g: Polynomial<i32, Var> = Polynomial::from_str("y+1").unwrap();
+        assert_eq!(
+            f.substitute(var!(x), &g),
+            Polynomial::from_str("y^3+2y^2+y+-3yz+-3z+2").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_map_homomorphism() {
+        let f: Polynomial<i32, Var> = Polynomial::from_str("3x^2+-y+8").unwrap();
+
+        // reduce modulo 7 and send x to y + z, y to yz
+        let image: Polynomial<Z7, Var, GradLex> = f.map_homomorphism(
+            |c| Z7::from_u64(c.rem_euclid(7) as u64),
+            |v| {
+                if v == var!(x) {
+                    Polynomial::from_str("y+z").unwrap()
+                } else {
+                    Polynomial::from_str("yz").unwrap()
+                }
+            },
+        );
+        assert_eq!(image, Polynomial::from_str("3y^2+5yz+3z^2+1").unwrap());
+    }
+}
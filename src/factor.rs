@@ -0,0 +1,1051 @@
+use itertools::Itertools;
+use num::{BigInt, Integer, One, Signed, ToPrimitive, Zero};
+
+use crate::{
+    dense::{add, derivative, mul, pseudo_remainder, sub, trim, Dense},
+    gcd::{coefficients, content_primitive, div_exact, from_coefficients, gcd, normalize},
+    monomial::Monomial,
+    order::Order,
+    polynomial::{HeadMonomial, Polynomial},
+    ring::{next_prime, FiniteField, GcdDomain, Rational, Ring, Zp},
+    term::{Degree, Term},
+    univariate::UnivariatePolynomial,
+    variable::Variable,
+};
+
+const OVERFLOW: &str = "Factor coefficient overflow";
+
+/// Number of points tried before Hensel lifting gives up.
+const POINTS: usize = 64;
+
+/// Irreducible factors with their multiplicities.
+pub type Factors<R, V, O> = Vec<(Polynomial<R, V, O>, usize)>;
+
+/// Distinct irreducible factors.
+pub type Irreducibles<R, V, O> = Vec<Polynomial<R, V, O>>;
+
+/// A coefficient ring over which polynomials can be factored.
+pub trait Factorable: GcdDomain {
+    /// The characteristic, zero for the integers.
+    fn characteristic() -> u64;
+
+    /// Irreducible factors `(g, m)` of a univariate polynomial, such that
+    /// `f = c * prod(g^m)` for a constant `c`, or an error when a factor
+    /// does not fit in the coefficient type.
+    fn factor_univariate(
+        f: &UnivariatePolynomial<Self>,
+    ) -> Result<Vec<(UnivariatePolynomial<Self>, usize)>, String>;
+
+    /// Irreducible factors of a square-free `f`, primitive and of positive
+    /// degree in `main`, found by Hensel lifting, or none when no point of
+    /// the other variables suits the lifting.
+    fn factor_square_free<V: Variable, O: Order>(
+        f: &Polynomial<Self, V, O>,
+        main: V,
+    ) -> Result<Option<Irreducibles<Self, V, O>>, String>;
+}
+
+impl<F: FiniteField> Factorable for F {
+    fn characteristic() -> u64 {
+        <F as FiniteField>::characteristic()
+    }
+
+    fn factor_univariate(
+        f: &UnivariatePolynomial<Self>,
+    ) -> Result<Vec<(UnivariatePolynomial<Self>, usize)>, String> {
+        Ok(f.factor())
+    }
+
+    fn factor_square_free<V: Variable, O: Order>(
+        f: &Polynomial<Self, V, O>,
+        main: V,
+    ) -> Result<Option<Irreducibles<Self, V, O>>, String> {
+        hensel(f, main, |g| g.clone(), |g| Ok(Some(g.clone())))
+    }
+}
+
+macro_rules! impl_integer_factorable {
+    ( $type:ty ) => {
+        impl Factorable for $type {
+            fn characteristic() -> u64 {
+                0
+            }
+
+            fn factor_univariate(
+                f: &UnivariatePolynomial<Self>,
+            ) -> Result<Vec<(UnivariatePolynomial<Self>, usize)>, String> {
+                let coeffs: BigPoly = f.coeffs().iter().map(|&c| BigInt::from(c)).collect();
+                factor_integer(&coeffs)
+                    .into_iter()
+                    .map(|(g, m)| {
+                        let coeffs = g
+                            .iter()
+                            .map(|c| <$type>::try_from(c).or(Err(OVERFLOW.to_owned())))
+                            .collect::<Result<_, _>>()?;
+                        Ok((UnivariatePolynomial::new(coeffs), m))
+                    })
+                    .collect()
+            }
+
+            fn factor_square_free<V: Variable, O: Order>(
+                f: &Polynomial<Self, V, O>,
+                main: V,
+            ) -> Result<Option<Irreducibles<Self, V, O>>, String> {
+                hensel_integer(f, main, |c| BigInt::from(c), |c| <$type>::try_from(c).ok())
+            }
+        }
+    };
+}
+
+impl_integer_factorable!(i32);
+impl_integer_factorable!(i64);
+
+impl Factorable for Rational {
+    fn characteristic() -> u64 {
+        0
+    }
+
+    /// Monic factors, found over the integers after clearing denominators,
+    /// by Gauss' lemma.
+    fn factor_univariate(
+        f: &UnivariatePolynomial<Self>,
+    ) -> Result<Vec<(UnivariatePolynomial<Self>, usize)>, String> {
+        let denom = f
+            .coeffs()
+            .iter()
+            .fold(BigInt::one(), |acc, c| acc.lcm(&BigInt::from(c.denom())));
+        let coeffs: BigPoly = f
+            .coeffs()
+            .iter()
+            .map(|c| BigInt::from(c.numer()) * (&denom / BigInt::from(c.denom())))
+            .collect();
+        factor_integer(&coeffs)
+            .into_iter()
+            .map(|(g, m)| {
+                let to_i64 = |c: &BigInt| c.to_i64().ok_or(OVERFLOW.to_owned());
+                let lead = to_i64(g.last().unwrap())?;
+                let coeffs = g
+                    .iter()
+                    .map(|c| Ok(Rational::new(to_i64(c)?, lead)))
+                    .collect::<Result<_, String>>()?;
+                Ok((UnivariatePolynomial::new(coeffs), m))
+            })
+            .collect()
+    }
+
+    /// Factors of `f` with its denominators cleared, lifted as over the
+    /// integers.
+    fn factor_square_free<V: Variable, O: Order>(
+        f: &Polynomial<Self, V, O>,
+        main: V,
+    ) -> Result<Option<Irreducibles<Self, V, O>>, String> {
+        let denom = f.iter().fold(BigInt::one(), |acc, m| {
+            acc.lcm(&BigInt::from(m.coeff.denom()))
+        });
+        let integral = f
+            .iter()
+            .map(|m| {
+                let numer =
+                    BigInt::from(m.coeff.numer()) * (&denom / BigInt::from(m.coeff.denom()));
+                let numer = numer.to_i64().ok_or(OVERFLOW.to_owned())?;
+                Ok(Monomial {
+                    coeff: Rational::new(numer, 1),
+                    term: m.term,
+                })
+            })
+            .collect::<Result<Polynomial<Self, V, O>, String>>()?;
+        hensel_integer(
+            &integral,
+            main,
+            |c| BigInt::from(c.numer()),
+            |c| c.to_i64().map(|c| Rational::new(c, 1)),
+        )
+    }
+}
+
+type BigPoly = Dense<BigInt>;
+
+/// `f` divided by the gcd of its coefficients, with a positive lead.
+fn primitive(f: &[BigInt]) -> BigPoly {
+    let content = f.iter().fold(BigInt::zero(), |acc, c| acc.gcd(c));
+    if content.is_zero() {
+        return vec![];
+    }
+    let content = if f.last().unwrap().is_negative() {
+        -content
+    } else {
+        content
+    };
+    f.iter().map(|c| c / &content).collect()
+}
+
+/// Primitive gcd with a positive lead, from the primitive remainder sequence.
+fn gcd_integer(f: &[BigInt], g: &[BigInt]) -> BigPoly {
+    let (mut a, mut b) = (primitive(f), primitive(g));
+    while !b.is_empty() {
+        let r = pseudo_remainder(&a, &b);
+        a = b;
+        b = primitive(&r);
+    }
+    a
+}
+
+/// `f / g` over the integers when `g` divides `f`.
+fn div_exact_integer(f: &[BigInt], g: &[BigInt]) -> Option<BigPoly> {
+    let mut r = f.to_vec();
+    let lead = g.last().unwrap();
+    let mut quotient = vec![BigInt::zero(); (f.len() + 1).saturating_sub(g.len())];
+    while r.len() >= g.len() {
+        let shift = r.len() - g.len();
+        let (c, remainder) = r.last().unwrap().div_rem(lead);
+        if !remainder.is_zero() {
+            return None;
+        }
+        for (a, b) in r[shift..].iter_mut().zip(g.iter()) {
+            *a -= &c * b;
+        }
+        quotient[shift] = c;
+        r = trim(r);
+    }
+    r.is_empty().then(|| trim(quotient))
+}
+
+/// Primitive square-free factors `(g, m)` of a primitive `f`, following
+/// Musser's algorithm.
+fn square_free_integer(f: &[BigInt]) -> Vec<(BigPoly, usize)> {
+    let mut factors: Vec<(BigPoly, usize)> = Default::default();
+    let mut c = gcd_integer(f, &derivative(f));
+    let mut w = div_exact_integer(f, &c).unwrap();
+    let mut i = 1;
+    while w.len() > 1 {
+        let y = gcd_integer(&w, &c);
+        let factor = div_exact_integer(&w, &y).unwrap();
+        if factor.len() > 1 {
+            factors.push((factor, i));
+        }
+        c = div_exact_integer(&c, &y).unwrap();
+        w = y;
+        i += 1;
+    }
+    factors
+}
+
+fn reduce<F: FiniteField>(f: &[BigInt]) -> UnivariatePolynomial<F> {
+    let p = BigInt::from(F::characteristic());
+    UnivariatePolynomial::new(
+        f.iter()
+            .map(|c| F::from_u64(c.mod_floor(&p).to_u64().unwrap()))
+            .collect(),
+    )
+}
+
+fn lift<F: FiniteField>(f: &UnivariatePolynomial<F>) -> BigPoly {
+    f.coeffs()
+        .iter()
+        .map(|&c| BigInt::from(c.to_u64()))
+        .collect()
+}
+
+/// Monic irreducible factors modulo the prime, when it keeps the degree and
+/// the image stays square-free.
+fn modular_factors<F: FiniteField>(f: &[BigInt]) -> Option<Vec<UnivariatePolynomial<F>>> {
+    let image = reduce::<F>(f);
+    if image.degree() != Some(f.len() - 1) || image.gcd(&image.derivative()).degree() != Some(0) {
+        return None;
+    }
+    Some(image.factor().into_iter().map(|(g, _)| g).collect())
+}
+
+fn modular_factor_count<F: FiniteField>(f: &[BigInt]) -> Option<usize> {
+    modular_factors::<F>(f).map(|factors| factors.len())
+}
+
+/// Lift `target = g * h` modulo `p` to modulo `p^k`, for coprime monic `g`
+/// and `h`, one power of `p` at a time.
+fn hensel_lift_two<F: FiniteField>(
+    target: &[BigInt],
+    g: &UnivariatePolynomial<F>,
+    h: &UnivariatePolynomial<F>,
+    k: u32,
+) -> (BigPoly, BigPoly) {
+    let p = BigInt::from(F::characteristic());
+    let (_, s, t) = g.extended_gcd(h);
+    let (mut lifted_g, mut lifted_h) = (lift(g), lift(h));
+
+    let mut power = p.clone();
+    for _ in 1..k {
+        // the error is divisible by p^j, and corrected modulo p^(j + 1)
+        let error: BigPoly = sub(target, &mul(&lifted_g, &lifted_h))
+            .into_iter()
+            .map(|c| c / &power)
+            .collect();
+        let error = reduce::<F>(&error);
+        let (q, a) = (&error * &t).div_rem(g);
+        let b = &(&error * &s) + &(&q * h);
+
+        let scale = |f: &UnivariatePolynomial<F>| -> BigPoly {
+            lift(f).into_iter().map(|c| c * &power).collect()
+        };
+        lifted_g = add(&lifted_g, &scale(&a));
+        lifted_h = add(&lifted_h, &scale(&b));
+        power *= &p;
+    }
+    (lifted_g, lifted_h)
+}
+
+/// Lift the monic factors of `f` modulo `p` to monic factors of `f / lc(f)`
+/// modulo `p^k`.
+fn hensel_lift<F: FiniteField>(
+    f: &[BigInt],
+    factors: &[UnivariatePolynomial<F>],
+    k: u32,
+) -> Vec<BigPoly> {
+    let modulus = BigInt::from(F::characteristic()).pow(k);
+    let inverse = f.last().unwrap().extended_gcd(&modulus).x;
+    let mut target: BigPoly = f
+        .iter()
+        .map(|c| (c * &inverse).mod_floor(&modulus))
+        .collect();
+
+    let mut lifted: Vec<BigPoly> = Default::default();
+    for (i, g) in factors[..factors.len() - 1].iter().enumerate() {
+        let h = factors[i + 1..]
+            .iter()
+            .fold(UnivariatePolynomial::one(), |acc, f| &acc * f);
+        let (lifted_g, lifted_h) = hensel_lift_two(&target, g, &h, k);
+        lifted.push(lifted_g);
+        target = lifted_h
+            .into_iter()
+            .map(|c| c.mod_floor(&modulus))
+            .collect();
+    }
+    lifted.push(target);
+    lifted
+}
+
+/// Irreducible factors of a primitive square-free `f` with a positive lead,
+/// by Zassenhaus' algorithm: factor modulo `p`, lift the factors past a bound
+/// on the coefficients of the factors of `f` and try products of subsets of
+/// them, smallest first.
+fn zassenhaus<F: FiniteField>(f: &[BigInt]) -> Vec<BigPoly> {
+    let factors = modular_factors::<F>(f).unwrap();
+    if factors.len() == 1 {
+        return vec![f.to_vec()];
+    }
+
+    // the factors of f, times the lead of f, have coefficients bounded by
+    // 2^n (n + 1) |f| |lc(f)|, and p^k must exceed twice the bound
+    let n = f.len() - 1;
+    let lead = f.last().unwrap().abs();
+    let norm = f.iter().map(|c| c.abs()).max().unwrap();
+    let bound = (BigInt::one() << (n + 1)) * BigInt::from(n + 1) * norm * &lead;
+    let p = BigInt::from(F::characteristic());
+    let (mut modulus, mut k) = (p.clone(), 1);
+    while modulus <= bound {
+        modulus *= &p;
+        k += 1;
+    }
+
+    let mut lifted = hensel_lift::<F>(f, &factors, k);
+    let half = &modulus / 2;
+    let symmetric = |c: BigInt| {
+        let c = c.mod_floor(&modulus);
+        if c > half {
+            c - &modulus
+        } else {
+            c
+        }
+    };
+
+    let mut remaining = f.to_vec();
+    let mut irreducibles: Vec<BigPoly> = Default::default();
+    let mut size = 1;
+    while 2 * size <= lifted.len() {
+        let found = (0..lifted.len()).combinations(size).find_map(|subset| {
+            let lead = vec![remaining.last().unwrap().clone()];
+            let candidate = subset.iter().fold(lead, |acc, &i| {
+                mul(&acc, &lifted[i])
+                    .into_iter()
+                    .map(|c| c.mod_floor(&modulus))
+                    .collect()
+            });
+            let candidate = primitive(&trim(candidate.into_iter().map(symmetric).collect()));
+            div_exact_integer(&remaining, &candidate).map(|q| (subset, candidate, q))
+        });
+
+        match found {
+            Some((subset, candidate, quotient)) => {
+                for &i in subset.iter().rev() {
+                    lifted.remove(i);
+                }
+                irreducibles.push(candidate);
+                remaining = quotient;
+            }
+            None => size += 1,
+        }
+    }
+    if remaining.len() > 1 {
+        irreducibles.push(primitive(&remaining));
+    }
+    irreducibles
+}
+
+/// Irreducible factors of a primitive square-free `f`, using the prime with
+/// the fewest modular factors among the first few suitable ones. Only the
+/// primes dividing the lead or the discriminant of `f` are unsuitable, so
+/// the search always ends.
+fn factor_square_free_integer(f: &[BigInt]) -> Vec<BigPoly> {
+    if f.len() <= 2 {
+        return vec![primitive(f)];
+    }
+
+    let (p, _) = std::iter::successors(Some(3), |&p| Some(next_prime(p)))
+        .filter_map(|p| {
+            Zp::with_modulus(p, || modular_factor_count::<Zp>(f)).map(|count| (p, count))
+        })
+        .take(4)
+        .min_by_key(|&(_, count)| count)
+        .unwrap();
+    Zp::with_modulus(p, || zassenhaus::<Zp>(f))
+}
+
+/// Irreducible primitive factors `(g, m)` of `f` over the integers, up to
+/// the content and sign.
+fn factor_integer(f: &[BigInt]) -> Vec<(BigPoly, usize)> {
+    let f = primitive(f);
+    let mut factors: Vec<(BigPoly, usize)> = square_free_integer(&f)
+        .into_iter()
+        .flat_map(|(g, m)| {
+            factor_square_free_integer(&g)
+                .into_iter()
+                .map(move |h| (h, m))
+        })
+        .collect();
+    factors.sort_by(|(g, _), (h, _)| {
+        g.len()
+            .cmp(&h.len())
+            .then_with(|| g.iter().rev().cmp(h.iter().rev()))
+    });
+    factors
+}
+
+fn variables<R: Factorable, V: Variable, O: Order>(f: &Polynomial<R, V, O>) -> Vec<V> {
+    f.iter()
+        .flat_map(|m| m.term.exps.into_iter().map(|(v, _)| v))
+        .sorted()
+        .dedup()
+        .collect()
+}
+
+fn degree_in<R: Ring, V: Variable, O: Order>(f: &Polynomial<R, V, O>, var: V) -> usize {
+    f.iter()
+        .filter_map(|m| {
+            m.term
+                .exps
+                .iter()
+                .find(|&&(v, _)| v == var)
+                .map(|&(_, e)| e)
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Total degree of `f` in the variables other than `main`.
+fn degree_besides<R: Ring, V: Variable, O: Order>(f: &Polynomial<R, V, O>, main: V) -> usize {
+    f.iter()
+        .map(|m| {
+            m.term
+                .exps
+                .iter()
+                .filter(|&&(v, _)| v != main)
+                .map(|&(_, e)| e)
+                .sum::<usize>()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Part of `f` of total degree `k` in the variables other than `main`, as a
+/// univariate polynomial in `main` for each term in the other variables.
+fn homogeneous_part<F: FiniteField, V: Variable, O: Order>(
+    f: &Polynomial<F, V, O>,
+    main: V,
+    k: usize,
+) -> Vec<(Term<V>, UnivariatePolynomial<F>)> {
+    let mut parts: Vec<(Term<V>, Vec<F>)> = Default::default();
+    for m in f.iter() {
+        let exp = m
+            .term
+            .exps
+            .iter()
+            .find(|&&(v, _)| v == main)
+            .map_or(0, |&(_, e)| e);
+        let rest: Term<V> = m.term.exps.iter().filter(|&&(v, _)| v != main).collect();
+        if rest.deg() != k {
+            continue;
+        }
+        let index = match parts.iter().position(|(term, _)| *term == rest) {
+            Some(index) => index,
+            None => {
+                parts.push((rest, vec![]));
+                parts.len() - 1
+            }
+        };
+        let coeffs = &mut parts[index].1;
+        if coeffs.len() <= exp {
+            coeffs.resize(exp + 1, F::zero());
+        }
+        coeffs[exp] = m.coeff;
+    }
+    parts
+        .into_iter()
+        .map(|(term, coeffs)| (term, UnivariatePolynomial::new(coeffs)))
+        .collect()
+}
+
+/// Lift `target = g0 * h0` modulo the other variables than `main` to
+/// `target = g * h` modulo their terms of total degree above `degree`, one
+/// degree at a time. Both `g` and `h` get `lead` as lead coefficient in
+/// `main`, for coprime `g0` and `h0` whose lead coefficients are the constant
+/// term of `lead`, so that the lifting is unique.
+fn hensel_lift_multivariate<F: FiniteField, V: Variable, O: Order>(
+    target: &Polynomial<F, V, O>,
+    lead: &Polynomial<F, V, O>,
+    g0: &UnivariatePolynomial<F>,
+    h0: &UnivariatePolynomial<F>,
+    main: V,
+    degree: usize,
+) -> (Polynomial<F, V, O>, Polynomial<F, V, O>) {
+    let (_, _, t) = g0.extended_gcd(h0);
+    let monomial = |c: F, exp: usize| {
+        let mut coeffs = vec![F::zero(); exp];
+        coeffs.push(c);
+        UnivariatePolynomial::new(coeffs)
+    };
+    let (g_degree, h_degree) = (g0.degree().unwrap(), h0.degree().unwrap());
+    let (mut g, mut h) = (g0.to_polynomial(main), h0.to_polynomial(main));
+
+    for k in 1..=degree {
+        // the error vanishes modulo the terms of degree k, and is corrected
+        // modulo those of degree k + 1
+        let mut errors = homogeneous_part(&(target - &(&g * &h)), main, k);
+        let leads = homogeneous_part(lead, main, k);
+        for (term, _) in leads.iter() {
+            if !errors.iter().any(|(t, _)| t == term) {
+                errors.push((term.clone(), UnivariatePolynomial::zero()));
+            }
+        }
+
+        for (term, error) in errors {
+            let c = leads
+                .iter()
+                .find(|(t, _)| *t == term)
+                .map_or(F::zero(), |(_, c)| c.lead_coeff());
+            let (g_lead, h_lead) = (monomial(c, g_degree), monomial(c, h_degree));
+            let error = &(&error - &(&g_lead * h0)) - &(&h_lead * g0);
+            let a = (&error * &t).div_rem(g0).1;
+            let b = (&error - &(&a * h0)).div_rem(g0).0;
+            g = &g + &((&g_lead + &a).to_polynomial(main) * &term);
+            h = &h + &((&h_lead + &b).to_polynomial(main) * &term);
+        }
+    }
+    (g, h)
+}
+
+/// Irreducible factors of a square-free `f`, primitive and of positive
+/// degree in `main`. The image of `f` in a finite field, given by `reduce`,
+/// is evaluated at a point of the other variables that keeps its degree in
+/// `main` and its square-freeness, and the factors of the univariate image
+/// are lifted by Hensel's lemma, products of fewer factors first. `recover`
+/// maps the lifted factors back to `R`, giving none when a lifted factor
+/// cannot be the image of a factor of `f`.
+fn hensel<F: FiniteField, R: Factorable, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    main: V,
+    reduce: impl Fn(&Polynomial<R, V, O>) -> Polynomial<F, V, O>,
+    recover: impl Fn(&Polynomial<F, V, O>) -> Result<Option<Polynomial<R, V, O>>, String>,
+) -> Result<Option<Irreducibles<R, V, O>>, String> {
+    let others: Vec<V> = variables(f).into_iter().filter(|&v| v != main).collect();
+    let degree = degree_in(f, main);
+
+    // points with coordinates below base, enough of them to try POINTS
+    let mut base: usize = 2;
+    while (base as u64) < F::characteristic() && base.saturating_pow(others.len() as u32) < POINTS {
+        base += 1;
+    }
+    let reduced = reduce(f);
+    let count = base.saturating_pow(others.len() as u32).min(POINTS);
+    let point = (0..count).find_map(|mut index| {
+        let point: Vec<F> = others
+            .iter()
+            .map(|_| {
+                let digit = index % base;
+                index /= base;
+                F::from_u64(digit as u64)
+            })
+            .collect();
+        let value = |v: V| others.iter().position(|&w| w == v).map(|i| point[i]);
+        let image = UnivariatePolynomial::from_polynomial(&reduced.partial_eval(&value), main)?;
+        (image.degree() == Some(degree) && image.gcd(&image.derivative()).degree() == Some(0))
+            .then_some((point, image))
+    });
+    let Some((point, image)) = point else {
+        return Ok(None);
+    };
+
+    let shift = |g: &Polynomial<F, V, O>, sign: F| -> Polynomial<F, V, O> {
+        others
+            .iter()
+            .zip(point.iter())
+            .fold(g.clone(), |acc, (&v, &a)| {
+                let var: Polynomial<F, V, O> = [
+                    Term::from_iter([(v, 1)]) * F::one(),
+                    Term::default() * (sign * a),
+                ]
+                .into_iter()
+                .collect();
+                acc.substitute(v, &var)
+            })
+    };
+
+    let mut factors: Vec<UnivariatePolynomial<F>> =
+        image.factor().into_iter().map(|(g, _)| g).collect();
+    let mut remaining = f.clone();
+    let mut irreducibles: Vec<Polynomial<R, V, O>> = Default::default();
+    let mut size = 1;
+    while 2 * size <= factors.len() {
+        let shifted = shift(&reduce(&remaining), F::one());
+        let lead = coefficients(&shifted, main).pop().unwrap();
+        let target = &lead * &shifted;
+        let c = lead.eval(&|_| F::zero());
+        let product = |indices: &mut dyn Iterator<Item = usize>| {
+            indices.fold(UnivariatePolynomial::constant(c), |acc, i| {
+                &acc * &factors[i]
+            })
+        };
+
+        let mut found = None;
+        for subset in (0..factors.len()).combinations(size) {
+            let g0 = product(&mut subset.iter().copied());
+            let h0 = product(&mut (0..factors.len()).filter(|i| !subset.contains(i)));
+            let degree = degree_besides(&target, main);
+            let (g, h) = hensel_lift_multivariate(&target, &lead, &g0, &h0, main, degree);
+            // the lifting of a true factor is exact, as its degrees are bounded
+            if &g * &h != target {
+                continue;
+            }
+            let Some(g) = recover(&shift(&g, F::zero() - F::one()))? else {
+                continue;
+            };
+            let g = from_coefficients(&content_primitive(&coefficients(&g, main)).1, main);
+            if let Some(quotient) = div_exact(&remaining, &g) {
+                found = Some((subset, normalize(g), quotient));
+                break;
+            }
+        }
+
+        match found {
+            Some((subset, g, quotient)) => {
+                for &i in subset.iter().rev() {
+                    factors.remove(i);
+                }
+                irreducibles.push(g);
+                remaining = quotient;
+            }
+            None => size += 1,
+        }
+    }
+    if degree_in(&remaining, main) > 0 {
+        irreducibles.push(normalize(remaining));
+    }
+    Ok(Some(irreducibles))
+}
+
+/// Hensel lifting over the integers, for `f` with integer coefficients given
+/// by `to_integer` and mapped back by `from_integer`, modulo a prime above
+/// twice a bound on the coefficients of the divisors of `lc(f) f`. Gives none
+/// when the bound exceeds the primes of [`Zp`].
+fn hensel_integer<R: Factorable, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    main: V,
+    to_integer: impl Fn(R) -> BigInt,
+    from_integer: impl Fn(&BigInt) -> Option<R>,
+) -> Result<Option<Irreducibles<R, V, O>>, String> {
+    // a divisor of g with degrees d_i has coefficients bounded by 2^(sum d_i)
+    // times the Mahler measure of g, itself bounded by the norm |g|_2
+    let lead = coefficients(f, main).pop().unwrap();
+    let degrees: Vec<usize> = variables(f)
+        .into_iter()
+        .map(|v| degree_in(f, v) + degree_in(&lead, v))
+        .collect();
+    let norm = |g: &Polynomial<R, V, O>| -> Vec<BigInt> {
+        g.iter().map(|m| to_integer(m.coeff).abs()).collect()
+    };
+    let bound = (BigInt::one() << degrees.iter().sum::<usize>())
+        * degrees
+            .iter()
+            .map(|&d| BigInt::from(d + 1))
+            .product::<BigInt>()
+        * norm(f).into_iter().max().unwrap()
+        * norm(&lead).into_iter().sum::<BigInt>();
+    let Some(p) = (&bound * 2u32)
+        .to_u64()
+        .filter(|&b| b < 1 << 62)
+        .map(next_prime)
+    else {
+        return Ok(None);
+    };
+
+    let modulus = BigInt::from(p);
+    Zp::with_modulus(p, || {
+        let reduce = |g: &Polynomial<R, V, O>| -> Polynomial<Zp, V, O> {
+            g.iter()
+                .map(|m| Monomial {
+                    coeff: Zp::from_u64(to_integer(m.coeff).mod_floor(&modulus).to_u64().unwrap()),
+                    term: m.term,
+                })
+                .collect()
+        };
+        let recover = |g: &Polynomial<Zp, V, O>| -> Result<Option<Polynomial<R, V, O>>, String> {
+            let mut monomials: Vec<Monomial<R, V>> = Default::default();
+            for m in g.iter() {
+                let c = BigInt::from(m.coeff.to_u64());
+                let c = if c > &modulus / 2 { c - &modulus } else { c };
+                if c.abs() > bound {
+                    return Ok(None);
+                }
+                let coeff = from_integer(&c).ok_or(OVERFLOW.to_owned())?;
+                monomials.push(Monomial {
+                    coeff,
+                    term: m.term,
+                });
+            }
+            Ok(Some(monomials.into_iter().collect()))
+        };
+        hensel(f, main, reduce, recover)
+    })
+}
+
+/// Square-free factors `(g, m)` of `f`, primitive in `main`, following
+/// Musser's algorithm with derivatives in `main`, or none in characteristic
+/// `p` when a factor has a multiplicity divisible by `p`.
+fn square_free<R: Factorable, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    main: V,
+) -> Option<Factors<R, V, O>> {
+    let mut factors: Factors<R, V, O> = Default::default();
+    let mut c = gcd(f, &f.derivative(main));
+    let mut w = div_exact(f, &c).unwrap();
+    let mut i = 1;
+    while degree_in(&w, main) > 0 {
+        let y = gcd(&w, &c);
+        let factor = div_exact(&w, &y).unwrap();
+        if degree_in(&factor, main) > 0 {
+            factors.push((factor, i));
+        }
+        c = div_exact(&c, &y).unwrap();
+        w = y;
+        i += 1;
+    }
+    (degree_in(&c, main) == 0).then_some(factors)
+}
+
+/// Irreducible factors with multiplicities of a normalized nonconstant `f`.
+/// The content in the variable of least degree is factored recursively, and
+/// the square-free parts of the primitive part by Hensel lifting, or by
+/// Kronecker's substitution when lifting does not apply.
+fn factor_primitive<R: Factorable, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+) -> Result<Factors<R, V, O>, String> {
+    let vars = variables(f);
+    if vars.len() <= 1 {
+        return kronecker(f);
+    }
+    let main = vars.into_iter().min_by_key(|&v| degree_in(f, v)).unwrap();
+
+    let (content, coeffs) = content_primitive(&coefficients(f, main));
+    let primitive = from_coefficients(&coeffs, main);
+    let mut factors = if content.deg() > 0 {
+        factor_primitive(&content)?
+    } else {
+        vec![]
+    };
+    let Some(parts) = square_free(&primitive, main) else {
+        factors.extend(kronecker(&primitive)?);
+        return Ok(factors);
+    };
+    for (g, m) in parts {
+        match R::factor_square_free(&g, main)? {
+            Some(irreducibles) => factors.extend(irreducibles.into_iter().map(|h| (h, m))),
+            None => factors.extend(kronecker(&g)?.into_iter().map(|(h, k)| (h, k * m))),
+        }
+    }
+    Ok(factors)
+}
+
+/// Ways of choosing `size` factors from a multiset with `counts[i]` copies
+/// of the factor `i`, as the number of copies chosen of each.
+fn sub_multisets(counts: &[usize], size: usize) -> Vec<Vec<usize>> {
+    match counts {
+        [] if size == 0 => vec![vec![]],
+        [] => vec![],
+        [count, rest @ ..] => (0..=size.min(*count))
+            .flat_map(|chosen| {
+                sub_multisets(rest, size - chosen)
+                    .into_iter()
+                    .map(move |mut choice| {
+                        choice.insert(0, chosen);
+                        choice
+                    })
+            })
+            .collect(),
+    }
+}
+
+/// Irreducible factors with multiplicities of a normalized nonconstant `f`,
+/// by Kronecker's substitution `x_i -> t^(w_i)`, where the weights `w_i` keep
+/// the exponents of every factor apart.
+///
+/// The image of every irreducible factor of `f` is a product of irreducible
+/// factors of the univariate image of `f`. Products of fewer factors are
+/// tried first, so that each divisor of `f` found is irreducible.
+fn kronecker<R: Factorable, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+) -> Result<Factors<R, V, O>, String> {
+    let vars = variables(f);
+    let bases: Vec<usize> = vars.iter().map(|&v| degree_in(f, v) + 1).collect();
+    let mut weights: Vec<usize> = Default::default();
+    let mut weight = 1usize;
+    for &base in bases.iter() {
+        weights.push(weight);
+        weight = weight
+            .checked_mul(base)
+            .ok_or("Too many variables for Kronecker substitution")?;
+    }
+
+    let mut coeffs: Vec<R> = Default::default();
+    for m in f.iter() {
+        let exp: usize = m
+            .term
+            .exps
+            .iter()
+            .map(|&(v, e)| e * weights[vars.binary_search(&v).unwrap()])
+            .sum();
+        if coeffs.len() <= exp {
+            coeffs.resize(exp + 1, R::zero());
+        }
+        coeffs[exp] = m.coeff;
+    }
+    let (candidates, mut counts): (Vec<UnivariatePolynomial<R>>, Vec<usize>) =
+        R::factor_univariate(&UnivariatePolynomial::new(coeffs))?
+            .into_iter()
+            .unzip();
+
+    let inverse = |g: &UnivariatePolynomial<R>| -> Polynomial<R, V, O> {
+        g.coeffs()
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| !c.is_zero())
+            .map(|(k, &coeff)| Monomial {
+                coeff,
+                term: vars
+                    .iter()
+                    .zip(weights.iter().zip(bases.iter()))
+                    .map(|(&v, (&weight, &base))| (v, k / weight % base))
+                    .collect(),
+            })
+            .collect()
+    };
+
+    let mut remaining = f.clone();
+    let mut factors: Vec<(Polynomial<R, V, O>, usize)> = Default::default();
+    let mut size = 1;
+    while 2 * size <= counts.iter().sum() {
+        let found = sub_multisets(&counts, size).into_iter().find_map(|choice| {
+            let product = candidates
+                .iter()
+                .zip(choice.iter())
+                .fold(UnivariatePolynomial::one(), |acc, (g, &chosen)| {
+                    (0..chosen).fold(acc, |acc, _| &acc * g)
+                });
+            let candidate = normalize(inverse(&product));
+            (candidate.deg() > 0 && div_exact(&remaining, &candidate).is_some())
+                .then_some((choice, candidate))
+        });
+
+        match found {
+            Some((choice, candidate)) => {
+                let mut multiplicity = 0;
+                while let Some(quotient) = div_exact(&remaining, &candidate) {
+                    remaining = quotient;
+                    multiplicity += 1;
+                    for (count, chosen) in counts.iter_mut().zip(choice.iter()) {
+                        *count -= chosen;
+                    }
+                }
+                factors.push((candidate, multiplicity));
+            }
+            None => size += 1,
+        }
+    }
+    if remaining.deg() > 0 {
+        factors.push((normalize(remaining), 1));
+    }
+    Ok(factors)
+}
+
+/// Factorization of `f` into irreducible factors with their multiplicities,
+/// sorted by degree. The factors are normalized as by
+/// [`gcd`], monic over a field and primitive with a positive
+/// lead over the integers, and the remaining constant comes first when it is
+/// not one.
+///
+/// Univariate polynomials are split by square-free decomposition, then by
+/// distinct and equal degree factorization over finite fields, or by
+/// Zassenhaus' algorithm with Hensel lifting over the integers. Multivariate
+/// ones are split into their content and square-free parts in the variable
+/// of least degree, whose factors are found by evaluating the other
+/// variables, factoring the univariate image and lifting its factors with
+/// Hensel's lemma, over a large prime field in characteristic zero. When no
+/// evaluation point keeps the degree and the square-freeness, as over small
+/// finite fields, or the coefficient bound exceeds the prime fields, the
+/// factors are found through Kronecker's substitution instead, whose cost
+/// grows quickly with the degrees and the number of variables.
+///
+/// Fails when a factor has coefficients that do not fit in `R`, as factors
+/// over the integers may have larger coefficients than `f`.
+pub fn factor<R: Factorable, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+) -> Result<Factors<R, V, O>, String> {
+    assert!(!f.is_zero(), "The zero polynomial has no factorization");
+
+    let content = f.iter().fold(R::zero(), |acc, m| acc.gcd(m.coeff));
+    let constant = content * f.lead_coeff().unit();
+    let primitive: Polynomial<R, V, O> = f
+        .iter()
+        .map(|m| Monomial {
+            coeff: m.coeff.exact_div(constant),
+            term: m.term,
+        })
+        .collect();
+
+    let mut factors = if primitive.deg() > 0 {
+        factor_primitive(&primitive)?
+    } else {
+        vec![]
+    };
+    factors.sort_by_key(|(q, _)| q.deg());
+
+    if !constant.is_one() {
+        factors.insert(0, ([Term::default() * constant].into_iter().collect(), 1));
+    }
+    Ok(factors)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{static_prime_field, var, variable::Var};
+
+    static_prime_field!(Z7(7:u32));
+
+    fn expand<R: Factorable>(factors: &[(Polynomial<R, Var>, usize)]) -> Polynomial<R, Var> {
+        factors.iter().fold(Polynomial::one(), |acc, (g, m)| {
+            (0..*m).fold(acc, |acc, _| acc * g)
+        })
+    }
+
+    #[test]
+    fn test_factor_integer() {
+        // x^4 + 1 is irreducible but splits modulo every prime
+        let f: Polynomial<i64, Var> = Polynomial::from_str("x^4+1").unwrap();
+        assert_eq!(factor(&f).unwrap(), vec![(f.clone(), 1)]);
+
+        let g: Polynomial<i64, Var> = Polynomial::from_str("-6x^2y+-6xy^2+6y^3+6").unwrap();
+        let factors = factor(&(&(&g * &g) * &f)).unwrap();
+        assert_eq!(factors[0], (Polynomial::from_str("36").unwrap(), 1));
+        assert_eq!(
+            &factors[1..],
+            &[
+                (Polynomial::from_str("x^2y+xy^2+-y^3+-1").unwrap(), 2),
+                (Polynomial::from_str("x^4+1").unwrap(), 1),
+            ]
+        );
+        assert_eq!(expand(&factors), &(&g * &g) * &f);
+
+        let h: Polynomial<i64, Var> = Polynomial::from_str("2x^3+-2xy^2+-3x^2z+3y^2z").unwrap();
+        // (x - y)(x + y)(2x - 3z)
+        let factors = factor(&h).unwrap();
+        assert_eq!(factors.len(), 3);
+        assert!(factors.iter().all(|(g, m)| g.deg() == 1 && *m == 1));
+        assert_eq!(expand(&factors), h);
+    }
+
+    #[test]
+    fn test_factor_integer_bad_primes() {
+        // every prime up to 59 divides the lead or the discriminant
+        let f: Polynomial<i64, Var> = Polynomial::from_str("3710369067405x^2+259106347").unwrap();
+        assert_eq!(factor(&f).unwrap(), vec![(f.clone(), 1)]);
+    }
+
+    #[test]
+    fn test_factor_hensel() {
+        let f: Polynomial<i64, Var> = Polynomial::from_str("x^2y+-3z+1").unwrap();
+        let g: Polynomial<i64, Var> = Polynomial::from_str("2xyz^2+3").unwrap();
+        let h: Polynomial<i64, Var> = Polynomial::from_str("x+y+-z").unwrap();
+        let product = &(&f * &g) * &h;
+        let factors = i64::factor_square_free(&product, var!(x)).unwrap().unwrap();
+        assert_eq!(factors.len(), 3);
+        for q in [f, g, h] {
+            assert!(factors.contains(&q));
+        }
+
+        // the lead coefficient y z^2 vanishes at points with y = 0 or z = 0
+        let f: Polynomial<Z7, Var> = Polynomial::from_str("x^2yz^2+x+y+3").unwrap();
+        let g: Polynomial<Z7, Var> = Polynomial::from_str("x+z^3").unwrap();
+        let factors = Z7::factor_square_free(&(&f * &g), var!(x)).unwrap();
+        assert_eq!(factors.map(|factors| factors.len()), Some(2));
+        let factors = factor(&(&f * &(&g * &g))).unwrap();
+        assert_eq!(factors, vec![(g, 2), (f, 1)]);
+    }
+
+    #[test]
+    fn test_factor_trivariate() {
+        // the square-free part needs the gcd of a trivariate product with its
+        // derivative, which the remainder sequence took seconds to compute
+        let f: Polynomial<i64, Var> = Polynomial::from_str("x^3y^2z+z^3+xy+2").unwrap();
+        let g: Polynomial<i64, Var> = Polynomial::from_str("x^2z^2+y^3+xz+3").unwrap();
+        let factors = factor(&(&f * &g)).unwrap();
+        assert_eq!(factors.len(), 2);
+        assert!(factors.contains(&(f, 1)) && factors.contains(&(g, 1)));
+    }
+
+    #[test]
+    fn test_factor_finite_field() {
+        // (x^2 + y)^7 = x^14 + y^7 over Z7
+        let f: Polynomial<Z7, Var> = Polynomial::from_str("x^14+y^7").unwrap();
+        let g: Polynomial<Z7, Var> = Polynomial::from_str("3xy+2").unwrap();
+
+        assert_eq!(
+            factor(&(&f * &(&g * &g))).unwrap(),
+            vec![
+                (Polynomial::from_str("2").unwrap(), 1),
+                (Polynomial::from_str("xy+3").unwrap(), 2),
+                (Polynomial::from_str("x^2+y").unwrap(), 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_factor_rational() {
+        // 4x^2 - 1 = 4(x - 1/2)(x + 1/2)
+        let f: Polynomial<Rational, Var> = Polynomial::from_str("4x^2y+-y").unwrap();
+        let factors = factor(&f).unwrap();
+        assert_eq!(factors[0], (Polynomial::from_str("4").unwrap(), 1));
+        assert_eq!(factors.len(), 4);
+        assert!(factors[1..].iter().all(|(g, m)| g.deg() == 1 && *m == 1));
+        assert_eq!(expand(&factors), f);
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    order::{Order, OrderedTerm},
+    polynomial::{HeadMonomial, Polynomial},
+    ring::Field,
+    term::Term,
+    variable::Variable,
+    zero_dim::{multiplication_matrices, standard_monomials},
+};
+
+struct EchelonRow<R: Field> {
+    pivot: usize,
+    vector: Vec<R>,
+    // coefficients of `vector` on the new standard monomials
+    combination: Vec<R>,
+}
+
+/// Convert a Gröbner basis of a zero-dimensional ideal from the order `O` to
+/// the reduced Gröbner basis for the order `P`, with the FGLM algorithm.
+///
+/// Terms are visited in increasing `P` order and their normal forms modulo
+/// `basis` are tested for linear dependency on those of the previous ones. A
+/// dependency gives a new basis element, otherwise the term is standard for
+/// `P`. Returns `None` when the ideal is not zero-dimensional.
+pub fn fglm<R: Field, V: Variable, O: Order, P: Order>(
+    basis: &[Polynomial<R, V, O>],
+    variables: &[V],
+) -> Option<Vec<Polynomial<R, V, P>>> {
+    let standard = standard_monomials(basis, variables)?;
+    let matrices = multiplication_matrices(basis, variables)?;
+
+    let mut new_basis: Vec<Polynomial<R, V, P>> = Default::default();
+    let mut new_standard: Vec<Term<V>> = Default::default();
+    let mut rows: Vec<EchelonRow<R>> = Default::default();
+
+    if standard.is_empty() {
+        return Some(vec![Polynomial::from_iter([Term::default() * R::one()])]);
+    }
+
+    // the normal form of 1 is 1, the smallest standard monomial
+    let mut one = vec![R::zero(); standard.len()];
+    one[0] = R::one();
+
+    let mut candidates: BTreeMap<OrderedTerm<V, P>, Vec<R>> = Default::default();
+    candidates.insert(Term::default().into(), one);
+
+    while let Some((term, vector)) = candidates.pop_first() {
+        if new_basis
+            .iter()
+            .any(|g| (&*term / &g.lead_term()).is_some())
+        {
+            continue;
+        }
+
+        let mut reduced = vector.clone();
+        let mut combination = vec![R::zero(); new_standard.len()];
+        for row in rows.iter() {
+            let coeff = reduced[row.pivot];
+            if coeff.is_zero() {
+                continue;
+            }
+            let factor = coeff / row.vector[row.pivot];
+            for (r, &v) in reduced.iter_mut().zip(row.vector.iter()) {
+                *r = *r - factor * v;
+            }
+            for (c, &v) in combination.iter_mut().zip(row.combination.iter()) {
+                *c = *c - factor * v;
+            }
+        }
+
+        match reduced.iter().position(|c| !c.is_zero()) {
+            None => {
+                // term + sum(combination[k] * new_standard[k]) lies in the ideal
+                new_basis.push(
+                    [(*term).clone() * R::one()]
+                        .into_iter()
+                        .chain(
+                            new_standard
+                                .iter()
+                                .zip(combination.iter())
+                                .map(|(t, &c)| t * c),
+                        )
+                        .collect(),
+                );
+            }
+            Some(pivot) => {
+                combination.push(R::one());
+                rows.push(EchelonRow {
+                    pivot,
+                    vector: reduced,
+                    combination,
+                });
+                for row in rows.iter_mut() {
+                    row.combination.resize(new_standard.len() + 1, R::zero());
+                }
+
+                for (&var, matrix) in variables.iter().zip(matrices.iter()) {
+                    let next = &*term * Term::from_iter([(var, 1)]);
+                    candidates
+                        .entry(next.into())
+                        .or_insert_with(|| matrix.mul_vec(&vector));
+                }
+                new_standard.push((*term).clone());
+            }
+        }
+    }
+
+    new_basis.sort_by(|left, right| P::cmp(&left.lead_term(), &right.lead_term()).reverse());
+    Some(new_basis)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        order::{GradLex, Lex},
+        polynomial::{buchberger, reduced_basis},
+        static_prime_field, var,
+        variable::Var,
+    };
+
+    static_prime_field!(Z101(101:u32));
+
+    #[test]
+    fn test_fglm_grad_lex_to_lex() {
+        let generators: Vec<Polynomial<Z101, Var, GradLex>> = vec![
+            Polynomial::from_str("x^2+y+-3").unwrap(),
+            Polynomial::from_str("y^2+x+-1").unwrap(),
+            Polynomial::from_str("xyz+-2").unwrap(),
+        ];
+        let variables = [var!(x), var!(y), var!(z)];
+        let grad_lex_basis = reduced_basis(&buchberger(&generators));
+
+        let lex_basis: Vec<Polynomial<Z101, Var, Lex>> = fglm(&grad_lex_basis, &variables).unwrap();
+
+        let lex_generators: Vec<Polynomial<Z101, Var, Lex>> =
+            generators.iter().map(|f| f.iter().collect()).collect();
+        assert_eq!(lex_basis, reduced_basis(&buchberger(&lex_generators)));
+
+        // the last element is the univariate eliminant in z
+        let eliminant = lex_basis.last().unwrap();
+        assert!(eliminant
+            .iter()
+            .all(|m| m.term.exps.iter().all(|&(v, _)| v == var!(z))));
+    }
+}
@@ -55,7 +55,7 @@ where
                 self.monomials
                     .iter()
                     .rev()
-                    .map(|(term, coeff)| if coeff.is_one() && term.exps.len() > 0 {
+                    .map(|(term, coeff)| if coeff.is_one() && !term.exps.is_empty() {
                         format!("{}", **term)
                     } else {
                         format!("{}{}", coeff, **term)
@@ -0,0 +1,563 @@
+use std::{collections::BTreeMap, iter::successors};
+
+use num::{BigInt, Integer, One, Signed, ToPrimitive, Zero};
+
+use crate::{
+    monomial::Monomial,
+    order::{Order, OrderedTerm},
+    polynomial::{sub_mono_poly_assign, HeadMonomial, Polynomial},
+    ring::{next_prime, FiniteField, GcdDomain, Zp},
+    term::{Degree, Term},
+    variable::Variable,
+};
+
+/// Coefficients in a main variable from the constant term up.
+pub(crate) type Coefficients<R, V, O> = Vec<Polynomial<R, V, O>>;
+
+/// Coefficients of `f` seen as a polynomial in `var`, from the constant term
+/// up, each a polynomial in the other variables.
+pub(crate) fn coefficients<R: GcdDomain, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    var: V,
+) -> Coefficients<R, V, O> {
+    let mut coeffs: Vec<Vec<Monomial<R, V>>> = Default::default();
+    for m in f.iter() {
+        let exp = m
+            .term
+            .exps
+            .iter()
+            .find(|&&(v, _)| v == var)
+            .map_or(0, |&(_, exp)| exp);
+        if coeffs.len() <= exp {
+            coeffs.resize(exp + 1, Default::default());
+        }
+        coeffs[exp].push(Monomial {
+            coeff: m.coeff,
+            term: m.term.exps.iter().filter(|&&(v, _)| v != var).collect(),
+        });
+    }
+    coeffs
+        .into_iter()
+        .map(|monomials| monomials.into_iter().collect())
+        .collect()
+}
+
+pub(crate) fn from_coefficients<R: GcdDomain, V: Variable, O: Order>(
+    coeffs: &[Polynomial<R, V, O>],
+    var: V,
+) -> Polynomial<R, V, O> {
+    coeffs
+        .iter()
+        .enumerate()
+        .flat_map(|(exp, c)| c * Term::from_iter([(var, exp)]))
+        .collect()
+}
+
+/// `f / g` when `g` divides `f` exactly.
+pub(crate) fn div_exact<R: GcdDomain, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    g: &Polynomial<R, V, O>,
+) -> Option<Polynomial<R, V, O>> {
+    let mut remainder = f.clone();
+    let mut quotient: Vec<Monomial<R, V>> = Default::default();
+
+    while !remainder.is_zero() {
+        let term = (remainder.lead_term() / g.lead_term())?;
+        let coeff = remainder.lead_coeff().exact_div(g.lead_coeff());
+        if coeff * g.lead_coeff() != remainder.lead_coeff() {
+            return None;
+        }
+        let mono = Monomial { coeff, term };
+        sub_mono_poly_assign(&mut remainder, &mono, g);
+        quotient.push(mono);
+    }
+    Some(quotient.into_iter().collect())
+}
+
+/// `f` divided by the unit of its lead coefficient.
+pub(crate) fn normalize<R: GcdDomain, V: Variable, O: Order>(
+    f: Polynomial<R, V, O>,
+) -> Polynomial<R, V, O> {
+    let unit = f.lead_coeff().unit();
+    if unit.is_one() {
+        f
+    } else {
+        f.iter()
+            .map(|m| Monomial {
+                coeff: m.coeff.exact_div(unit),
+                term: m.term,
+            })
+            .collect()
+    }
+}
+
+/// Pseudo-remainder of `lc(b)^(deg(a) - deg(b) + 1) * a` by `b`, both given
+/// by their coefficients in the main variable.
+pub(crate) fn pseudo_remainder<R: GcdDomain, V: Variable, O: Order>(
+    a: &[Polynomial<R, V, O>],
+    b: &[Polynomial<R, V, O>],
+) -> Coefficients<R, V, O> {
+    let mut a = a.to_vec();
+    let lead = b.last().unwrap();
+    let mut steps = (a.len() + 1).saturating_sub(b.len());
+    while a.len() >= b.len() {
+        let shift = a.len() - b.len();
+        let factor = a.pop().unwrap();
+        for c in a.iter_mut() {
+            *c = &*c * lead;
+        }
+        for (c, d) in a[shift..].iter_mut().zip(b.iter()) {
+            *c = &*c - &factor * d;
+        }
+        while a.last().is_some_and(|c| c.is_zero()) {
+            a.pop();
+        }
+        steps -= 1;
+    }
+    // the degree dropped by more than one at some steps
+    for c in a.iter_mut() {
+        *c = (0..steps).fold(c.clone(), |acc, _| acc * lead);
+    }
+    a
+}
+
+/// The content, the gcd of the coefficients in the main variable, and the
+/// primitive part.
+pub(crate) fn content_primitive<R: GcdDomain, V: Variable, O: Order>(
+    coeffs: &[Polynomial<R, V, O>],
+) -> (Polynomial<R, V, O>, Coefficients<R, V, O>) {
+    let content = coeffs
+        .iter()
+        .fold(Polynomial::zero(), |acc, c| gcd(&acc, c));
+    let primitive = coeffs
+        .iter()
+        .map(|c| div_exact(c, &content).unwrap())
+        .collect();
+    (content, primitive)
+}
+
+/// Sparse polynomial with arbitrary precision integer coefficients.
+type IntegerPoly<V, O> = BTreeMap<OrderedTerm<V, O>, BigInt>;
+
+/// `f` divided by its content, with a positive lead coefficient.
+fn primitive_integer<V: Variable, O: Order>(f: IntegerPoly<V, O>) -> IntegerPoly<V, O> {
+    let content = f.values().fold(BigInt::zero(), |acc, c| acc.gcd(c));
+    let content = if f.last_key_value().unwrap().1.is_negative() {
+        -content
+    } else {
+        content
+    };
+    f.into_iter()
+        .map(|(term, c)| (term, c / &content))
+        .collect()
+}
+
+/// Whether `h` divides `f` over the integers, by repeated top reductions.
+fn divides_integer<V: Variable, O: Order>(mut f: IntegerPoly<V, O>, h: &IntegerPoly<V, O>) -> bool {
+    let (h_lead, h_coeff) = h.last_key_value().unwrap();
+    while let Some((lead, coeff)) = f.pop_last() {
+        let Some(m) = &*lead / &**h_lead else {
+            return false;
+        };
+        let (c, remainder) = coeff.div_rem(h_coeff);
+        if !remainder.is_zero() {
+            return false;
+        }
+        for (term, h_c) in h.range(..h_lead.clone()) {
+            let term: OrderedTerm<V, O> = (&m * &**term).into();
+            let value = f.remove(&term).unwrap_or_default() - &c * h_c;
+            if !value.is_zero() {
+                f.insert(term, value);
+            }
+        }
+    }
+    true
+}
+
+/// Gcd of nonzero `f` and `g` over the integers, from their gcds modulo the
+/// primes above `2^31`, so that no intermediate value overflows.
+///
+/// The monic images, scaled by the gcd of the lead coefficients, are
+/// combined by Chinese remaindering. Primes whose image has a larger lead
+/// term are unlucky and skipped. The primitive part of the combination is
+/// the gcd once it is stable and divides `f` and `g`, since a common divisor
+/// with the lead term of the gcd is the gcd.
+///
+/// Panics when the gcd has coefficients that do not fit in `R`.
+fn modular_gcd<R: GcdDomain, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    g: &Polynomial<R, V, O>,
+) -> Polynomial<R, V, O> {
+    let to_integers = |f: &Polynomial<R, V, O>| -> IntegerPoly<V, O> {
+        f.monomials
+            .iter()
+            .map(|(term, &c)| (term.clone(), c.to_integer().unwrap()))
+            .collect()
+    };
+    let content = |f: &IntegerPoly<V, O>| f.values().fold(BigInt::zero(), |acc, c| acc.gcd(c));
+    let (f, g) = (to_integers(f), to_integers(g));
+    let common_content = content(&f).gcd(&content(&g));
+    let (f, g) = (primitive_integer(f), primitive_integer(g));
+    let (f_lead, g_lead) = (f.last_key_value().unwrap().1, g.last_key_value().unwrap().1);
+    let lead = f_lead.gcd(g_lead);
+
+    let from_integers = |h: &IntegerPoly<V, O>| -> Polynomial<R, V, O> {
+        let monomials = h
+            .iter()
+            .map(|(term, c)| {
+                let c = R::from_integer(&(c * &common_content)).expect("Gcd coefficient overflow");
+                (term.clone(), c)
+            })
+            .collect();
+        Polynomial { monomials }
+    };
+
+    let mut accumulated: Option<(BigInt, IntegerPoly<V, O>)> = None;
+    let mut previous: Option<IntegerPoly<V, O>> = None;
+    for p in successors(Some(next_prime(1 << 31)), |&p| Some(next_prime(p))) {
+        let prime = BigInt::from(p);
+        if (f_lead % &prime).is_zero() || (g_lead % &prime).is_zero() {
+            continue;
+        }
+        let image: IntegerPoly<V, O> = Zp::with_modulus(p, || {
+            let residue = |c: &BigInt| Zp::from_u64(c.mod_floor(&prime).to_u64().unwrap());
+            let reduce = |f: &IntegerPoly<V, O>| -> Polynomial<Zp, V, O> {
+                let monomials = f
+                    .iter()
+                    .map(|(term, c)| (term.clone(), residue(c)))
+                    .filter(|(_, c)| !c.is_zero())
+                    .collect();
+                Polynomial { monomials }
+            };
+            let scale = residue(&lead);
+            gcd(&reduce(&f), &reduce(&g))
+                .monomials
+                .into_iter()
+                .map(|(term, c)| (term, BigInt::from((c * scale).to_u64())))
+                .collect()
+        });
+
+        let image_lead = image.last_key_value().unwrap().0;
+        if image_lead.deg() == 0 {
+            return from_integers(&BTreeMap::from([(image_lead.clone(), BigInt::one())]));
+        }
+        let (modulus, residues) = match accumulated.as_mut() {
+            Some((modulus, residues)) if residues.last_key_value().unwrap().0 == image_lead => {
+                // Chinese remaindering with the new residues modulo p
+                let inverse = modulus.mod_floor(&prime).extended_gcd(&prime).x;
+                for term in image.keys() {
+                    residues.entry(term.clone()).or_default();
+                }
+                for (term, a) in residues.iter_mut() {
+                    let b = image.get(term).cloned().unwrap_or_default();
+                    let t = ((b - &*a) * &inverse).mod_floor(&prime);
+                    *a += &*modulus * t;
+                }
+                *modulus *= &prime;
+                (modulus, residues)
+            }
+            Some((_, residues)) if residues.last_key_value().unwrap().0 < image_lead => continue,
+            _ => {
+                previous = None;
+                let (modulus, residues) = accumulated.insert((prime, image));
+                (modulus, residues)
+            }
+        };
+
+        let half = &*modulus / 2;
+        let candidate = primitive_integer(
+            residues
+                .iter()
+                .map(|(term, c)| {
+                    let c = if c > &half { c - &*modulus } else { c.clone() };
+                    (term.clone(), c)
+                })
+                .filter(|(_, c)| !c.is_zero())
+                .collect(),
+        );
+        if previous.as_ref() == Some(&candidate)
+            && divides_integer(f.clone(), &candidate)
+            && divides_integer(g.clone(), &candidate)
+        {
+            return from_integers(&candidate);
+        }
+        previous = Some(candidate);
+    }
+    unreachable!("There are infinitely many primes")
+}
+
+/// Gcd over a field of the primitive pseudo-remainder sequence in `var`,
+/// times the gcd of the contents.
+fn remainder_sequence_gcd<R: GcdDomain, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    g: &Polynomial<R, V, O>,
+    var: V,
+) -> Polynomial<R, V, O> {
+    let (f_content, mut a) = content_primitive(&coefficients(f, var));
+    let (g_content, mut b) = content_primitive(&coefficients(g, var));
+    if a.len() < b.len() {
+        (a, b) = (b, a);
+    }
+    while b.len() > 1 {
+        let r = pseudo_remainder(&a, &b);
+        a = b;
+        b = if r.is_empty() {
+            r
+        } else {
+            content_primitive(&r).1
+        };
+    }
+    let primitive = if b.is_empty() {
+        from_coefficients(&a, var)
+    } else {
+        Polynomial::one()
+    };
+
+    normalize(gcd(&f_content, &g_content) * primitive)
+}
+
+/// `f` as a polynomial in the variables other than `var`, whose
+/// coefficients are polynomials in `var`, keyed by the terms.
+fn split<R: GcdDomain, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    var: V,
+) -> BTreeMap<OrderedTerm<V, O>, Polynomial<R, V, O>> {
+    let mut parts: BTreeMap<OrderedTerm<V, O>, Vec<Monomial<R, V>>> = Default::default();
+    for m in f.iter() {
+        let (inner, outer): (Vec<&(V, usize)>, Vec<_>) =
+            m.term.exps.iter().partition(|&&(v, _)| v == var);
+        parts
+            .entry(outer.into_iter().collect::<Term<V>>().into())
+            .or_default()
+            .push(Monomial {
+                coeff: m.coeff,
+                term: inner.into_iter().collect(),
+            });
+    }
+    parts
+        .into_iter()
+        .map(|(term, monomials)| (term, monomials.into_iter().collect()))
+        .collect()
+}
+
+/// Gcd over a field of `f` and `g` with several variables, by evaluating
+/// `var` at the points `1, 2, ...` and interpolating the gcds of the images,
+/// or none when the field has too few points.
+///
+/// The monic images are scaled by the value of the gcd of the lead
+/// coefficients, polynomials in `var`, and combined by Newton interpolation. Points whose image has a larger lead
+/// term are unlucky and skipped. The primitive part in `var` of the
+/// interpolant is the gcd once it is stable and divides `f` and `g`.
+fn interpolation_gcd<R: GcdDomain, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    g: &Polynomial<R, V, O>,
+    var: V,
+) -> Option<Polynomial<R, V, O>> {
+    let content = |f: &Polynomial<R, V, O>| {
+        split(f, var)
+            .values()
+            .fold(Polynomial::zero(), |acc, c| gcd(&acc, c))
+    };
+    let (f_content, g_content) = (content(f), content(g));
+    let common_content = gcd(&f_content, &g_content);
+    let f = div_exact(f, &f_content).unwrap();
+    let g = div_exact(g, &g_content).unwrap();
+
+    let lead = |f: &Polynomial<R, V, O>| split(f, var).pop_last().unwrap().1;
+    let (f_lead, g_lead) = (lead(&f), lead(&g));
+    let lead = gcd(&f_lead, &g_lead);
+    // the interpolant has at most this degree in var
+    let bound = coefficients(&f, var).len().min(coefficients(&g, var).len())
+        + coefficients(&lead, var).len();
+
+    let eval = |h: &Polynomial<R, V, O>, a: R| h.partial_eval(&|v| (v == var).then_some(a));
+    let value = |h: &Polynomial<R, V, O>, a: R| {
+        eval(h, a)
+            .monomials
+            .into_values()
+            .next()
+            .unwrap_or(R::zero())
+    };
+    let x: Polynomial<R, V, O> = [Term::from_iter([(var, 1)]) * R::one()]
+        .into_iter()
+        .collect();
+
+    // the interpolant, the product of the x - a for its points and their number
+    let mut accumulated: Option<(Polynomial<R, V, O>, _, usize)> = None;
+    let mut a = R::zero();
+    loop {
+        a = a + R::one();
+        if a.is_zero() {
+            return None;
+        }
+        if value(&f_lead, a).is_zero() || value(&g_lead, a).is_zero() {
+            continue;
+        }
+        let image = gcd(&eval(&f, a), &eval(&g, a)) * (Term::default() * value(&lead, a));
+        if image.lead_term().deg() == 0 {
+            return Some(normalize(common_content));
+        }
+        let point: Polynomial<R, V, O> = &x - &([Term::default() * a].into_iter().collect());
+
+        match accumulated.as_mut() {
+            Some((interpolant, modulus, count))
+                if interpolant_lead(interpolant, var) == image.lead_term() =>
+            {
+                let difference = &image - &eval(interpolant, a);
+                if difference.is_zero() {
+                    let candidate = div_exact(interpolant, &content(interpolant)).unwrap();
+                    if div_exact(&f, &candidate).is_some() && div_exact(&g, &candidate).is_some() {
+                        return Some(normalize(common_content * candidate));
+                    }
+                } else {
+                    let scale = R::one().exact_div(value(modulus, a));
+                    *interpolant =
+                        &*interpolant + &(&difference * &*modulus * (Term::default() * scale));
+                }
+                *modulus = &*modulus * &point;
+                *count += 1;
+                if *count > bound {
+                    accumulated = None;
+                }
+            }
+            Some((interpolant, _, _))
+                if O::cmp(&interpolant_lead(interpolant, var), &image.lead_term()).is_lt() =>
+            {
+                continue
+            }
+            _ => accumulated = Some((image, point, 1)),
+        }
+    }
+}
+
+/// The lead term of `h` seen as a polynomial in the variables other than
+/// `var`.
+fn interpolant_lead<R: GcdDomain, V: Variable, O: Order>(
+    h: &Polynomial<R, V, O>,
+    var: V,
+) -> Term<V> {
+    (*split(h, var).pop_last().unwrap().0).clone()
+}
+
+/// Greatest common divisor over a field, normalized to be monic, or over the
+/// integers, normalized with a positive lead coefficient.
+///
+/// Over a field with several variables the gcd is interpolated from its
+/// values at points of the variable of least degree, see
+/// `interpolation_gcd`. With one variable, or when the field is too small,
+/// it is computed recursively on the greatest variable, as the gcd of the
+/// contents times the primitive part of the last nonzero remainder of the
+/// primitive pseudo-remainder sequence. Over the integers, where the
+/// remainders overflow, it is computed modulo primes instead, see
+/// `modular_gcd`, and panics when the gcd itself does not fit in `R`.
+pub fn gcd<R: GcdDomain, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    g: &Polynomial<R, V, O>,
+) -> Polynomial<R, V, O> {
+    if f.is_zero() {
+        return normalize(g.clone());
+    }
+    if g.is_zero() {
+        return normalize(f.clone());
+    }
+    if f.lead_coeff().to_integer().is_some() {
+        return modular_gcd(f, g);
+    }
+
+    let mut degrees: BTreeMap<V, usize> = Default::default();
+    for m in f.iter().chain(g.iter()) {
+        for &(v, exp) in m.term.exps.iter() {
+            let degree = degrees.entry(v).or_default();
+            *degree = (*degree).max(exp);
+        }
+    }
+    match degrees.len() {
+        0 => {
+            let coeff = f.lead_coeff().gcd(g.lead_coeff());
+            [Term::default() * coeff].into_iter().collect()
+        }
+        1 => remainder_sequence_gcd(f, g, *degrees.keys().next().unwrap()),
+        _ => {
+            let (&var, _) = degrees.iter().min_by_key(|&(_, degree)| degree).unwrap();
+            interpolation_gcd(f, g, var)
+                .unwrap_or_else(|| remainder_sequence_gcd(f, g, *degrees.keys().last().unwrap()))
+        }
+    }
+}
+
+/// Least common multiple, normalized as [`gcd`], zero when `f` or `g` is.
+pub fn lcm<R: GcdDomain, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    g: &Polynomial<R, V, O>,
+) -> Polynomial<R, V, O> {
+    if f.is_zero() || g.is_zero() {
+        return Polynomial::zero();
+    }
+    normalize(div_exact(&(f * g), &gcd(f, g)).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{static_prime_field, var, variable::Var};
+
+    static_prime_field!(Z7(7:u32));
+
+    #[test]
+    fn test_pseudo_remainder() {
+        // the degree drops from 3 to 0 in a single step
+        let a: Polynomial<i32, Var> = Polynomial::from_str("x^3+1").unwrap();
+        let b = Polynomial::from_str("2x").unwrap();
+        let r = pseudo_remainder(&coefficients(&a, var!(x)), &coefficients(&b, var!(x)));
+        assert_eq!(
+            from_coefficients(&r, var!(x)),
+            Polynomial::from_str("8").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_gcd_integers() {
+        let common: Polynomial<i64, Var> = Polynomial::from_str("2x+-3yz+1").unwrap();
+        let f = &common * &Polynomial::from_str("3x^2+-y").unwrap();
+        let g = &common * &Polynomial::from_str("-6xz+9y^2+3").unwrap();
+
+        // the contents 1 and 3 contribute nothing
+        assert_eq!(gcd(&f, &g), common);
+        let minus_two: Polynomial<i64, Var> = Polynomial::from_str("-2").unwrap();
+        assert_eq!(gcd(&(&f * &minus_two), &g), common);
+        assert_eq!(
+            gcd(
+                &Polynomial::from_str("6x+6y").unwrap(),
+                &Polynomial::<i64, Var>::from_str("-4y^2+-4xy").unwrap()
+            ),
+            Polynomial::from_str("2x+2y").unwrap()
+        );
+
+        let h: Polynomial<i64, Var> = Polynomial::from_str("x+y+z").unwrap();
+        assert!(gcd(&f, &h).is_one());
+    }
+
+    #[test]
+    fn test_gcd_integers_overflow() {
+        // the pseudo-remainders of the cofactors overflow i32
+        let common: Polynomial<i32, Var> = Polynomial::from_str("3x^2+-5y+7").unwrap();
+        let f = &common * &Polynomial::from_str("17x^4+11xy^3+-13").unwrap();
+        let g = &common * &Polynomial::from_str("19x^4+-23x^3+29y^2").unwrap();
+        assert_eq!(gcd(&f, &g), common);
+    }
+
+    #[test]
+    fn test_gcd_lcm_finite_field() {
+        let f: Polynomial<Z7, Var> = Polynomial::from_str("3x^2y+3xy^2").unwrap();
+        let g: Polynomial<Z7, Var> = Polynomial::from_str("2x^2+4xy+2y^2").unwrap();
+
+        assert_eq!(gcd(&f, &g), Polynomial::from_str("x+y").unwrap());
+        assert_eq!(
+            lcm(&f, &g),
+            Polynomial::from_str("x^3y+2x^2y^2+xy^3").unwrap()
+        );
+        assert!(lcm(&f, &Polynomial::zero()).is_zero());
+    }
+}
@@ -0,0 +1,417 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use num::{rational::Ratio, One, Zero};
+
+use crate::{
+    order::Order,
+    polynomial::{
+        is_homogeneous, monic, normal_form, reduced_basis, sploy, HeadMonomial, Polynomial,
+    },
+    ring::{Field, Ring},
+    term::{lcm, Degree, Term},
+    variable::Variable,
+};
+
+/// Hilbert–Poincaré series `N(t) / (1 - t)^n` of `k[x_1, ..., x_n]/I`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HilbertSeries {
+    numerator: Vec<i64>,
+    variables: usize,
+}
+
+fn trim(mut p: Vec<i64>) -> Vec<i64> {
+    while p.last() == Some(&0) {
+        p.pop();
+    }
+    p
+}
+
+fn sub_series(left: &[i64], right: &[i64]) -> Vec<i64> {
+    let mut result = vec![0; left.len().max(right.len())];
+    for (i, &c) in left.iter().enumerate() {
+        result[i] += c;
+    }
+    for (i, &c) in right.iter().enumerate() {
+        result[i] -= c;
+    }
+    trim(result)
+}
+
+fn mul_series(left: &[i64], right: &[i64]) -> Vec<i64> {
+    if left.is_empty() || right.is_empty() {
+        return vec![];
+    }
+    let mut result = vec![0; left.len() + right.len() - 1];
+    for (i, &a) in left.iter().enumerate() {
+        for (j, &b) in right.iter().enumerate() {
+            result[i + j] += a * b;
+        }
+    }
+    trim(result)
+}
+
+fn shift_series(p: &[i64], k: usize) -> Vec<i64> {
+    if p.is_empty() {
+        return vec![];
+    }
+    let mut result = vec![0; k];
+    result.extend_from_slice(p);
+    result
+}
+
+fn minimize<V: Variable>(generators: &[Term<V>]) -> Vec<Term<V>> {
+    let mut minimal: Vec<Term<V>> = Default::default();
+    for (i, m) in generators.iter().enumerate() {
+        let redundant = generators
+            .iter()
+            .enumerate()
+            .any(|(j, other)| i != j && (m / other).is_some() && (m != other || j < i));
+        if !redundant {
+            minimal.push(m.clone());
+        }
+    }
+    minimal
+}
+
+/// Numerator of the Hilbert series of a monomial ideal, using
+/// `N(<J, m>) = N(J) - t^deg(m) N(J : m)`.
+fn numerator<V: Variable>(generators: &[Term<V>]) -> Vec<i64> {
+    if generators.iter().any(|m| m.exps.is_empty()) {
+        return vec![];
+    }
+
+    let generators = minimize(generators);
+
+    let coprime = generators.iter().enumerate().all(|(i, m)| {
+        generators[i + 1..].iter().all(|other| {
+            m.exps
+                .iter()
+                .all(|(var, _)| other.exps.iter().all(|(v, _)| v != var))
+        })
+    });
+    if coprime {
+        return generators.iter().fold(vec![1], |acc, m| {
+            mul_series(&acc, &sub_series(&[1], &shift_series(&[1], m.deg())))
+        });
+    }
+
+    let (last, rest) = generators.split_last().unwrap();
+    let colon: Vec<Term<V>> = rest
+        .iter()
+        .map(|m| (&lcm(m, last) / last).unwrap())
+        .collect();
+
+    sub_series(
+        &numerator(rest),
+        &shift_series(&numerator(&colon), last.deg()),
+    )
+}
+
+fn binomial(n: i64, k: i64) -> i64 {
+    if k < 0 || n < k {
+        return 0;
+    }
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
+impl HilbertSeries {
+    pub fn numerator(&self) -> &[i64] {
+        &self.numerator
+    }
+
+    pub fn num_variables(&self) -> usize {
+        self.variables
+    }
+
+    /// Numerator `h(t)` and exponent `d` of the reduced form `h(t) / (1 - t)^d`
+    /// with `h(1) != 0`.
+    pub fn reduced(&self) -> (Vec<i64>, usize) {
+        let mut h = self.numerator.clone();
+        let mut d = self.variables;
+
+        while !h.is_empty() && d > 0 && h.iter().sum::<i64>() == 0 {
+            // divide by (1 - t), the remainder is zero since h(1) = 0
+            let mut quotient = Vec::with_capacity(h.len() - 1);
+            let mut acc = 0;
+            for &c in h[..h.len() - 1].iter() {
+                acc += c;
+                quotient.push(acc);
+            }
+            h = trim(quotient);
+            d -= 1;
+        }
+
+        (h, d)
+    }
+
+    /// Krull dimension of `k[x]/I`, the dimension of the affine variety, or
+    /// `None` when `I` is the whole ring.
+    pub fn dimension(&self) -> Option<usize> {
+        if self.numerator.is_empty() {
+            None
+        } else {
+            Some(self.reduced().1)
+        }
+    }
+
+    /// Degree of the variety, `h(1)` in the reduced form.
+    pub fn degree(&self) -> i64 {
+        self.reduced().0.iter().sum()
+    }
+
+    /// Coefficient of `t^s` in the series.
+    pub fn hilbert_function(&self, s: usize) -> i64 {
+        let (h, d) = self.reduced();
+        let s = s as i64;
+        if d == 0 {
+            return h.get(s as usize).cloned().unwrap_or(0);
+        }
+        h.iter()
+            .enumerate()
+            .map(|(k, &c)| c * binomial(s - k as i64 + d as i64 - 1, d as i64 - 1))
+            .sum()
+    }
+
+    /// Coefficients, by increasing degree in `s`, of the polynomial equal to
+    /// the Hilbert function for all large enough `s`.
+    pub fn hilbert_polynomial(&self) -> Vec<Ratio<i64>> {
+        let (h, d) = self.reduced();
+        if d == 0 {
+            return vec![];
+        }
+
+        let factorial: i64 = (1..d as i64).product();
+        let mut result = vec![Ratio::zero(); d];
+        for (k, &c) in h.iter().enumerate() {
+            // c * binomial(s - k + d - 1, d - 1) as a polynomial in s
+            let mut term = vec![Ratio::from_integer(c)];
+            for j in 0..d as i64 - 1 {
+                let root = Ratio::from_integer(d as i64 - 1 - k as i64 - j);
+                let mut next = vec![Ratio::zero(); term.len() + 1];
+                for (i, &a) in term.iter().enumerate() {
+                    next[i] += a * root;
+                    next[i + 1] += a;
+                }
+                term = next;
+            }
+            for (i, a) in term.into_iter().enumerate() {
+                result[i] += a / Ratio::from_integer(factorial);
+            }
+        }
+
+        while result.last().is_some_and(|c| c.is_zero()) {
+            result.pop();
+        }
+        result
+    }
+}
+
+impl Display for HilbertSeries {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (h, d) = self.reduced();
+        let numerator: Vec<String> = h
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| !c.is_zero())
+            .map(|(k, c)| match k {
+                0 => format!("{}", c),
+                1 if c.is_one() => "t".to_string(),
+                1 => format!("{}t", c),
+                _ if c.is_one() => format!("t^{}", k),
+                _ => format!("{}t^{}", c, k),
+            })
+            .collect();
+        let numerator = if numerator.is_empty() {
+            "0".to_string()
+        } else {
+            numerator.join(" + ")
+        };
+
+        match d {
+            0 => write!(f, "{}", numerator),
+            1 => write!(f, "({})/(1 - t)", numerator),
+            _ => write!(f, "({})/(1 - t)^{}", numerator, d),
+        }
+    }
+}
+
+/// Hilbert series of `k[variables]/M` for the monomial ideal `M`.
+pub fn monomial_hilbert_series<V: Variable>(
+    generators: &[Term<V>],
+    variables: &[V],
+) -> HilbertSeries {
+    HilbertSeries {
+        numerator: numerator(generators),
+        variables: variables.len(),
+    }
+}
+
+/// Hilbert series of the ideal of lead terms of `basis`.
+///
+/// For a Gröbner basis of a homogeneous ideal this is the Hilbert series of
+/// `k[x]/I`. The dimension holds for any ideal and order; for the degree of
+/// an affine ideal the order must be graded, e.g. [`GradLex`](crate::order::GradLex).
+pub fn hilbert_series<R: Ring, V: Variable, H: HeadMonomial<R, V>>(
+    basis: &[H],
+    variables: &[V],
+) -> HilbertSeries {
+    let leads: Vec<Term<V>> = basis
+        .iter()
+        .filter(|g| !g.lead_coeff().is_zero())
+        .map(|g| g.lead_term())
+        .collect();
+    monomial_hilbert_series(&leads, variables)
+}
+
+enum Critical<R: Ring, V: Variable, O: Order> {
+    Generator(Polynomial<R, V, O>),
+    Pair(usize, usize),
+}
+
+/// Buchberger's algorithm for homogeneous generators, driven by the known
+/// Hilbert series `target` of the ideal, e.g. computed modulo a prime.
+///
+/// Critical pairs and generators are processed by increasing degree. As soon
+/// as the Hilbert function of the current lead terms agrees with `target` in
+/// some degree, the remaining pairs of that degree would reduce to zero and
+/// are dropped. The Hilbert function is computed once per degree and lowered
+/// by one for each new element. A wrong `target` gives an incomplete basis.
+pub fn hilbert_driven_buchberger<R: Field, V: Variable, O: Order>(
+    polys: &[Polynomial<R, V, O>],
+    variables: &[V],
+    target: &HilbertSeries,
+) -> Vec<Polynomial<R, V, O>> {
+    assert!(
+        polys.iter().all(is_homogeneous),
+        "The generators must be homogeneous"
+    );
+
+    let mut queue: BTreeMap<usize, Vec<Critical<R, V, O>>> = Default::default();
+    for f in polys.iter().filter(|f| !f.is_zero()) {
+        queue
+            .entry(f.deg())
+            .or_default()
+            .push(Critical::Generator(f.clone()));
+    }
+
+    let mut g: Vec<Polynomial<R, V, O>> = Default::default();
+
+    while let Some((degree, items)) = queue.pop_first() {
+        let leads: Vec<Term<V>> = g.iter().map(|g_i| g_i.lead_term()).collect();
+        let mut hilbert = monomial_hilbert_series(&leads, variables).hilbert_function(degree);
+        let expected = target.hilbert_function(degree);
+        for item in items {
+            if hilbert <= expected {
+                break;
+            }
+
+            let f = match item {
+                Critical::Generator(f) => f,
+                Critical::Pair(i, j) => sploy(&g[i], &g[j]),
+            };
+            let remainder = normal_form(&f, &g);
+            if remainder.is_zero() {
+                continue;
+            }
+
+            let lead = remainder.lead_term();
+            for (i, g_i) in g.iter().enumerate() {
+                let m = lcm(&lead, &g_i.lead_term());
+                // product criterion: coprime lead terms give a zero reduction
+                if m.deg() == lead.deg() + g_i.lead_term().deg() {
+                    continue;
+                }
+                queue
+                    .entry(m.deg())
+                    .or_default()
+                    .push(Critical::Pair(i, g.len()));
+            }
+            g.push(monic(&remainder));
+            // the new lead term has this degree and is not in the ideal of
+            // the previous ones, so it is the only new monomial in the degree
+            hilbert -= 1;
+        }
+    }
+
+    reduced_basis(&g)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{order::GradLex, polynomial::buchberger, static_prime_field, var, variable::Var};
+
+    static_prime_field!(Z7(7:u32));
+    static_prime_field!(Z101(101:u32));
+
+    #[test]
+    fn test_monomial_hilbert_series() {
+        let generators: Vec<Term<Var>> = vec![
+            Term::from_str("x^2").unwrap(),
+            Term::from_str("xy").unwrap(),
+        ];
+        let series = monomial_hilbert_series(&generators, &[var!(x), var!(y)]);
+
+        assert_eq!(series.numerator(), &[1, 0, -2, 1]);
+        assert_eq!(series.reduced(), (vec![1, 1, -1], 1));
+        assert_eq!(series.dimension(), Some(1));
+        assert_eq!(series.degree(), 1);
+        assert_eq!(
+            (0..5)
+                .map(|s| series.hilbert_function(s))
+                .collect::<Vec<_>>(),
+            vec![1, 2, 1, 1, 1]
+        );
+        assert_eq!(series.hilbert_polynomial(), vec![Ratio::one()]);
+        assert_eq!(series.to_string(), "(1 + t + -1t^2)/(1 - t)");
+    }
+
+    #[test]
+    fn test_twisted_cubic() {
+        let generators: Vec<Polynomial<i32, Var, GradLex>> = vec![
+            Polynomial::from_str("xz+-y^2").unwrap(),
+            Polynomial::from_str("wx+-yz").unwrap(),
+            Polynomial::from_str("wy+-z^2").unwrap(),
+        ];
+        let variables = [var!(x), var!(y), var!(z), Var(22)];
+        let series = hilbert_series(&buchberger(&generators), &variables);
+
+        assert_eq!(series.reduced(), (vec![1, 2], 2));
+        assert_eq!(series.dimension(), Some(2));
+        assert_eq!(series.degree(), 3);
+        assert_eq!(series.hilbert_function(4), 13);
+        assert_eq!(
+            series.hilbert_polynomial(),
+            vec![Ratio::one(), Ratio::from_integer(3)]
+        );
+
+        let whole: Vec<Term<Var>> = vec![Term::default()];
+        assert_eq!(
+            monomial_hilbert_series(&whole, &variables).dimension(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_hilbert_driven_buchberger() {
+        let generators: Vec<Polynomial<Z7, Var, GradLex>> = vec![
+            Polynomial::from_str("x^2+yz").unwrap(),
+            Polynomial::from_str("y^2+xz").unwrap(),
+            Polynomial::from_str("z^2+2xy").unwrap(),
+        ];
+        let variables = [var!(x), var!(y), var!(z)];
+        let modular_series = hilbert_series(&buchberger(&generators), &variables);
+
+        let generators: Vec<Polynomial<Z101, Var, GradLex>> = vec![
+            Polynomial::from_str("x^2+yz").unwrap(),
+            Polynomial::from_str("y^2+xz").unwrap(),
+            Polynomial::from_str("z^2+2xy").unwrap(),
+        ];
+        let basis = hilbert_driven_buchberger(&generators, &variables, &modular_series);
+
+        assert_eq!(basis, reduced_basis(&buchberger(&generators)));
+        assert_eq!(hilbert_series(&basis, &variables), modular_series);
+    }
+}
@@ -1,12 +1,40 @@
+pub mod critical;
+mod dense;
+pub mod derivative;
+pub mod eval;
 pub mod exponent;
+pub mod factor;
+pub mod fglm;
 pub mod fmt;
+pub mod gcd;
+pub mod hilbert;
 pub mod join;
+pub mod lift;
+pub mod matrix;
+pub mod modular;
+pub mod module;
 pub mod monomial;
+pub mod nullstellensatz;
+pub mod numeric;
 pub mod ops;
 pub mod order;
 pub mod parser;
 pub mod poly_iter;
 pub mod polynomial;
+pub mod primary;
+pub mod quotient;
+pub mod radical;
+pub mod real_roots;
+pub mod resolution;
+pub mod resultant;
 pub mod ring;
+pub mod solve;
+pub mod syzygy;
 pub mod term;
+pub mod univariate;
 pub mod variable;
+pub mod walk;
+pub mod zero_dim;
+
+#[doc(hidden)]
+pub use num;
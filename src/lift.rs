@@ -0,0 +1,146 @@
+use num::Zero;
+
+use crate::{
+    module::ModuleElement,
+    monomial::Monomial,
+    order::Order,
+    polynomial::{buchberger_loop, normal_form_quotients, HeadMonomial, Polynomial},
+    ring::Field,
+    term::lcm,
+    variable::Variable,
+};
+
+/// A Gröbner basis and the representations of its elements in terms of the
+/// generators.
+pub type TrackedBasis<R, V, O> = (Vec<Polynomial<R, V, O>>, Vec<ModuleElement<R, V, O>>);
+
+/// The S-polynomial of `basis[i]` and `basis[j]` with monic lead terms,
+/// together with its representation in terms of the generators.
+pub(crate) fn spair<R: Field, V: Variable, O: Order>(
+    basis: &[Polynomial<R, V, O>],
+    reprs: &[ModuleElement<R, V, O>],
+    i: usize,
+    j: usize,
+) -> (Polynomial<R, V, O>, ModuleElement<R, V, O>) {
+    let m = lcm(&basis[i].lead_term(), &basis[j].lead_term());
+    let multiplier = |k: usize| -> Polynomial<R, V, O> {
+        let mono: Monomial<R, V> =
+            (&m / &basis[k].lead_term()).unwrap() * basis[k].lead_coeff().inv();
+        [mono].into_iter().collect()
+    };
+    let (a, b) = (multiplier(i), multiplier(j));
+    (
+        &a * &basis[i] - &b * &basis[j],
+        &a * &reprs[i] - &b * &reprs[j],
+    )
+}
+
+/// Subtract the representations of the basis elements weighted by
+/// `quotients` from `repr`.
+pub(crate) fn sub_quotients<R: Field, V: Variable, O: Order>(
+    repr: ModuleElement<R, V, O>,
+    quotients: &[Polynomial<R, V, O>],
+    reprs: &[ModuleElement<R, V, O>],
+) -> ModuleElement<R, V, O> {
+    quotients
+        .iter()
+        .zip(reprs.iter())
+        .filter(|(q, _)| !q.is_zero())
+        .fold(repr, |acc, (q, r)| acc - q * r)
+}
+
+/// Gröbner basis of the ideal of `generators`, each element with its
+/// representation `g = sum(repr[i] * generators[i])`.
+///
+/// This is [`buchberger`](crate::polynomial::buchberger) with the option of
+/// tracking representations: they are the rows of the transformation matrix
+/// from the generators to the basis, updated along the same loop by the
+/// quotients of each reduction. The basis is not reduced.
+pub fn tracked_buchberger<R: Field, V: Variable, O: Order>(
+    generators: &[Polynomial<R, V, O>],
+) -> TrackedBasis<R, V, O> {
+    let rank = generators.len();
+    let (mut basis, mut reprs): (Vec<_>, Vec<_>) = generators
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| !f.is_zero())
+        .map(|(i, f)| (f.clone(), ModuleElement::unit(rank, i)))
+        .unzip();
+
+    buchberger_loop(&mut basis, |basis, i, j| {
+        // coprime lead terms always reduce to zero
+        if lcm(&basis[i].lead_term(), &basis[j].lead_term())
+            == basis[i].lead_term() * basis[j].lead_term()
+        {
+            return None;
+        }
+
+        let (s, repr) = spair(basis, &reprs, i, j);
+        let (quotients, remainder) = normal_form_quotients(&s, basis);
+        if remainder.is_zero() {
+            return None;
+        }
+        reprs.push(sub_quotients(repr, &quotients, &reprs));
+        Some(remainder)
+    });
+    (basis, reprs)
+}
+
+/// Coefficients `h` with `f = sum(h[i] * generators[i])`, a certificate of
+/// the membership of `f` in the ideal of `generators`, or none when `f` is
+/// not a member.
+pub fn lift<R: Field, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    generators: &[Polynomial<R, V, O>],
+) -> Option<Vec<Polynomial<R, V, O>>> {
+    let (basis, reprs) = tracked_buchberger(generators);
+    let (quotients, remainder) = normal_form_quotients(f, &basis);
+    if !remainder.is_zero() {
+        return None;
+    }
+
+    let repr = quotients
+        .iter()
+        .zip(reprs.iter())
+        .fold(ModuleElement::zero(generators.len()), |acc, (q, r)| {
+            acc + q * r
+        });
+    Some(repr.into_components())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{ring::Rational, variable::Var};
+
+    #[test]
+    fn test_tracked_buchberger() {
+        let generators: Vec<Polynomial<Rational, Var>> = vec![
+            Polynomial::from_str("x^2+y").unwrap(),
+            Polynomial::from_str("xy+-1").unwrap(),
+        ];
+        let (basis, reprs) = tracked_buchberger(&generators);
+        assert!(basis.len() > generators.len());
+        for (g, repr) in basis.iter().zip(reprs.iter()) {
+            assert_eq!(&repr.dot(&generators), g);
+        }
+    }
+
+    #[test]
+    fn test_lift() {
+        let generators: Vec<Polynomial<Rational, Var>> = vec![
+            Polynomial::from_str("x^2+y").unwrap(),
+            Polynomial::from_str("xy+-1").unwrap(),
+        ];
+        // y^2 + x = y(x^2 + y) - x(xy - 1)
+        let f = Polynomial::from_str("y^2+x").unwrap();
+        let h = lift(&f, &generators).unwrap();
+        assert_eq!(h.len(), 2);
+        let combination = &h[0] * &generators[0] + &h[1] * &generators[1];
+        assert_eq!(combination, f);
+
+        assert!(lift(&Polynomial::from_str("x").unwrap(), &generators).is_none());
+    }
+}
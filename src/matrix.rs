@@ -0,0 +1,115 @@
+use std::ops::{Index, IndexMut, Mul};
+
+use crate::ring::Ring;
+
+/// Dense row-major matrix over a ring.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Matrix<R: Ring> {
+    rows: usize,
+    cols: usize,
+    data: Vec<R>,
+}
+
+impl<R: Ring> Matrix<R> {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            data: vec![R::zero(); rows * cols],
+        }
+    }
+
+    pub fn identity(size: usize) -> Self {
+        let mut matrix = Self::zeros(size, size);
+        for i in 0..size {
+            matrix[(i, i)] = R::one();
+        }
+        matrix
+    }
+
+    pub fn from_rows(rows: Vec<Vec<R>>) -> Self {
+        let cols = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "Rows of different lengths"
+        );
+        Self {
+            rows: rows.len(),
+            cols,
+            data: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, i: usize) -> &[R] {
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    pub fn col(&self, j: usize) -> Vec<R> {
+        (0..self.rows).map(|i| self[(i, j)]).collect()
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut transposed = Self::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                transposed[(j, i)] = self[(i, j)];
+            }
+        }
+        transposed
+    }
+
+    pub fn mul_vec(&self, vector: &[R]) -> Vec<R> {
+        assert_eq!(self.cols, vector.len(), "Dimension mismatch");
+        (0..self.rows)
+            .map(|i| {
+                self.row(i)
+                    .iter()
+                    .zip(vector.iter())
+                    .fold(R::zero(), |acc, (&a, &b)| acc + a * b)
+            })
+            .collect()
+    }
+}
+
+impl<R: Ring> Index<(usize, usize)> for Matrix<R> {
+    type Output = R;
+
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl<R: Ring> IndexMut<(usize, usize)> for Matrix<R> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+impl<R: Ring> Mul<&Matrix<R>> for &Matrix<R> {
+    type Output = Matrix<R>;
+
+    fn mul(self, rhs: &Matrix<R>) -> Self::Output {
+        assert_eq!(self.cols, rhs.rows, "Dimension mismatch");
+        let mut product = Matrix::zeros(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self[(i, k)];
+                if a.is_zero() {
+                    continue;
+                }
+                for j in 0..rhs.cols {
+                    product[(i, j)] = product[(i, j)] + a * rhs[(k, j)];
+                }
+            }
+        }
+        product
+    }
+}
@@ -0,0 +1,422 @@
+use std::{collections::BTreeMap, iter::successors, ops::Rem};
+
+use num::{integer::Roots, BigInt, BigRational, Integer, One, Signed, ToPrimitive, Zero};
+
+use crate::{
+    order::{Order, OrderedTerm},
+    polynomial::{buchberger, reduced_basis, HeadMonomial, Polynomial},
+    ring::{next_prime, FiniteField, Rational, Zp},
+    term::{lcm, Degree, Term},
+    variable::Variable,
+};
+
+/// Reduced Gröbner basis of the image of the input modulo a prime.
+struct ModularImage<V: Variable, O: Order> {
+    prime: u64,
+    leads: Vec<Term<V>>,
+    coefficients: Vec<BTreeMap<OrderedTerm<V, O>, u64>>,
+}
+
+/// Residues of the images sharing the same lead terms, combined by CRT.
+struct Accumulator<V: Variable, O: Order> {
+    primes: usize,
+    modulus: BigInt,
+    leads: Vec<Term<V>>,
+    coefficients: Vec<BTreeMap<OrderedTerm<V, O>, BigInt>>,
+    /// The last reconstruction, from fewer primes.
+    previous: Option<Vec<BigPoly<V, O>>>,
+}
+
+type BigPoly<V, O> = BTreeMap<OrderedTerm<V, O>, BigRational>;
+
+/// Polynomial with arbitrary precision rational coefficients, as computed by
+/// [`groebner_over_q`].
+#[derive(Debug)]
+pub struct BigRationalPolynomial<V: Variable, O: Order> {
+    monomials: BigPoly<V, O>,
+}
+
+impl<V: Variable, O: Order> Clone for BigRationalPolynomial<V, O> {
+    fn clone(&self) -> Self {
+        Self {
+            monomials: self.monomials.clone(),
+        }
+    }
+}
+
+impl<V: Variable, O: Order> PartialEq for BigRationalPolynomial<V, O> {
+    fn eq(&self, other: &Self) -> bool {
+        self.monomials == other.monomials
+    }
+}
+
+impl<V: Variable, O: Order> Eq for BigRationalPolynomial<V, O> {}
+
+impl<V: Variable, O: Order> BigRationalPolynomial<V, O> {
+    /// Terms with their nonzero coefficients, from the smallest term up.
+    pub fn iter(&self) -> impl Iterator<Item = (&Term<V>, &BigRational)> {
+        self.monomials.iter().map(|(term, coeff)| (&**term, coeff))
+    }
+
+    /// The same polynomial over [`Rational`], when its coefficients fit.
+    pub fn to_rational(&self) -> Option<Polynomial<Rational, V, O>> {
+        self.monomials
+            .iter()
+            .map(|(term, coeff)| {
+                let coeff = Rational::new(coeff.numer().to_i64()?, coeff.denom().to_i64()?);
+                Some((term.clone(), coeff))
+            })
+            .collect::<Option<_>>()
+            .map(|monomials| Polynomial { monomials })
+    }
+}
+
+impl<V: Variable, O: Order> From<&Polynomial<Rational, V, O>> for BigRationalPolynomial<V, O> {
+    fn from(f: &Polynomial<Rational, V, O>) -> Self {
+        Self {
+            monomials: to_big(f),
+        }
+    }
+}
+
+fn inv_mod(a: u64, p: u64) -> u64 {
+    let mut result: u128 = 1;
+    let mut base = a as u128 % p as u128;
+    let mut exp = p - 2;
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = result * base % p as u128;
+        }
+        base = base * base % p as u128;
+        exp /= 2;
+    }
+    result as u64
+}
+
+fn modular_image<F, V: Variable, O: Order>(
+    polys: &[Polynomial<Rational, V, O>],
+) -> Option<ModularImage<V, O>>
+where
+    F: FiniteField + Rem<F, Output = F>,
+{
+    let p = F::characteristic();
+    let mut images: Vec<Polynomial<F, V, O>> = Default::default();
+
+    for f in polys.iter().filter(|f| !f.is_zero()) {
+        let mut image: Polynomial<F, V, O> = Default::default();
+        for (term, coeff) in f.monomials.iter() {
+            let denom = coeff.denom().rem_euclid(p as i64) as u64;
+            // the prime divides a denominator, the image is not defined
+            if denom == 0 {
+                return None;
+            }
+            let numer = coeff.numer().rem_euclid(p as i64) as u64;
+            let value: F = F::from_u64(numer) / F::from_u64(denom);
+            if !value.is_zero() {
+                image.monomials.insert(term.clone(), value);
+            }
+        }
+        if !image.is_zero() {
+            images.push(image);
+        }
+    }
+
+    let basis = reduced_basis(&buchberger(&images));
+    Some(ModularImage {
+        prime: p,
+        leads: basis.iter().map(|g| g.lead_term()).collect(),
+        coefficients: basis
+            .iter()
+            .map(|g| {
+                g.monomials
+                    .iter()
+                    .map(|(term, &coeff)| (term.clone(), coeff.to_u64()))
+                    .collect()
+            })
+            .collect(),
+    })
+}
+
+impl<V: Variable, O: Order> Accumulator<V, O> {
+    fn new(image: ModularImage<V, O>) -> Self {
+        Self {
+            primes: 1,
+            previous: None,
+            modulus: BigInt::from(image.prime),
+            leads: image.leads,
+            coefficients: image
+                .coefficients
+                .into_iter()
+                .map(|g| {
+                    g.into_iter()
+                        .map(|(term, coeff)| (term, BigInt::from(coeff)))
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Chinese remaindering of the accumulated residues with a new image.
+    fn combine(&mut self, image: &ModularImage<V, O>) {
+        let p = BigInt::from(image.prime);
+        let m_inv = BigInt::from(inv_mod((&self.modulus % &p).to_u64().unwrap(), image.prime));
+
+        for (acc, residues) in self.coefficients.iter_mut().zip(image.coefficients.iter()) {
+            for term in residues.keys() {
+                acc.entry(term.clone()).or_default();
+            }
+            for (term, a) in acc.iter_mut() {
+                let b = BigInt::from(residues.get(term).copied().unwrap_or(0));
+                let t = ((b - &*a) * &m_inv).mod_floor(&p);
+                *a += &self.modulus * t;
+            }
+        }
+
+        self.modulus *= p;
+        self.primes += 1;
+    }
+
+    fn reconstruct(&self) -> Option<Vec<BigPoly<V, O>>> {
+        self.coefficients
+            .iter()
+            .map(|g| {
+                g.iter()
+                    .filter(|(_, a)| !a.is_zero())
+                    .map(|(term, a)| {
+                        rational_reconstruction(a, &self.modulus).map(|c| (term.clone(), c))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Find `r / s` congruent to `a` modulo `m` with `|r|, |s| <= sqrt(m / 2)`,
+/// with the extended Euclidean algorithm stopped halfway.
+fn rational_reconstruction(a: &BigInt, m: &BigInt) -> Option<BigRational> {
+    let bound: BigInt = Roots::sqrt(&(m / 2));
+    let (mut r0, mut r1) = (m.clone(), a.mod_floor(m));
+    let (mut s0, mut s1) = (BigInt::zero(), BigInt::one());
+
+    while r1 > bound {
+        let q = &r0 / &r1;
+        (r0, r1) = (r1.clone(), &r0 - &q * &r1);
+        (s0, s1) = (s1.clone(), &s0 - &q * &s1);
+    }
+
+    if s1.abs() > bound || !s1.gcd(m).is_one() {
+        return None;
+    }
+    if s1.is_negative() {
+        (r1, s1) = (-r1, -s1);
+    }
+    Some(BigRational::new(r1, s1))
+}
+
+fn to_big<V: Variable, O: Order>(f: &Polynomial<Rational, V, O>) -> BigPoly<V, O> {
+    f.monomials
+        .iter()
+        .map(|(term, coeff)| {
+            let value = BigRational::new(coeff.numer().into(), coeff.denom().into());
+            (term.clone(), value)
+        })
+        .collect()
+}
+
+/// Whether repeated top reductions by `basis` bring `f` to zero, computed
+/// exactly over the rationals.
+fn reduces_to_zero<V: Variable, O: Order>(mut f: BigPoly<V, O>, basis: &[BigPoly<V, O>]) -> bool {
+    while let Some((lead, coeff)) = f.pop_last() {
+        let divisor = basis.iter().find_map(|g| {
+            let (g_lead, g_coeff) = g.last_key_value()?;
+            (&*lead / &**g_lead).map(|m| (m, g_coeff, g))
+        });
+        let Some((m, g_coeff, g)) = divisor else {
+            return false;
+        };
+
+        let c = coeff / g_coeff;
+        for (term, g_coeff) in g.range(..g.last_key_value().unwrap().0.clone()) {
+            let term: OrderedTerm<V, O> = (&m * &**term).into();
+            let value = f.remove(&term).unwrap_or_default() - &c * g_coeff;
+            if !value.is_zero() {
+                f.insert(term, value);
+            }
+        }
+    }
+    true
+}
+
+/// Exact check that `candidate` is a Gröbner basis of an ideal containing
+/// `polys`: every input and every S-polynomial reduces to zero. That the
+/// ideal is not larger is not checked, as it would take expressing the
+/// candidate in terms of `polys` over the rationals.
+fn verify<V: Variable, O: Order>(
+    basis: &[BigPoly<V, O>],
+    polys: &[Polynomial<Rational, V, O>],
+) -> bool {
+    if !polys.iter().all(|f| reduces_to_zero(to_big(f), basis)) {
+        return false;
+    }
+
+    basis.iter().enumerate().all(|(i, f)| {
+        basis[i + 1..].iter().all(|g| {
+            let (f_lead, f_coeff) = f.last_key_value().unwrap();
+            let (g_lead, g_coeff) = g.last_key_value().unwrap();
+            let m = lcm(f_lead, g_lead);
+            // product criterion: coprime lead terms give a zero reduction
+            if m.deg() == f_lead.deg() + g_lead.deg() {
+                return true;
+            }
+
+            let (m_f, m_g) = ((&m / &**f_lead).unwrap(), (&m / &**g_lead).unwrap());
+            let mut s: BigPoly<V, O> = Default::default();
+            for (term, coeff) in f.iter() {
+                s.insert((&m_f * &**term).into(), coeff / f_coeff);
+            }
+            for (term, coeff) in g.iter() {
+                let term: OrderedTerm<V, O> = (&m_g * &**term).into();
+                let value = s.remove(&term).unwrap_or_default() - coeff / g_coeff;
+                if !value.is_zero() {
+                    s.insert(term, value);
+                }
+            }
+            reduces_to_zero(s, basis)
+        })
+    })
+}
+
+/// Reduced Gröbner basis over the rationals, computed with a multi-modular
+/// algorithm to avoid the coefficient swell of a direct computation.
+///
+/// Reduced bases of the images modulo the primes above `2^31` are computed
+/// with [`buchberger`]. Only the images sharing the most frequent lead terms
+/// are kept, the other primes being unlucky. They are combined by Chinese
+/// remaindering and lifted back to rationals by rational reconstruction,
+/// with as many primes as the coefficients need. The candidate is accepted
+/// once it agrees with the reconstruction from fewer primes and is verified
+/// exactly to be a Gröbner basis of an ideal containing `polys`.
+///
+/// The verification does not rule out a larger ideal. The result is the
+/// basis of the ideal of `polys` unless the unlucky primes, which divide the
+/// numerators or denominators met along the computation over the rationals,
+/// agree on a candidate, such as the unit ideal, before the lucky ones do.
+/// With primes above `2^31` this takes unusually large input coefficients.
+pub fn groebner_over_q<V: Variable, O: Order>(
+    polys: &[Polynomial<Rational, V, O>],
+) -> Vec<BigRationalPolynomial<V, O>> {
+    let primes = successors(Some(next_prime(1 << 31)), |&p| Some(next_prime(p)));
+    let images = primes.filter_map(|p| Zp::with_modulus(p, || modular_image::<Zp, V, O>(polys)));
+
+    let mut groups: Vec<Accumulator<V, O>> = Default::default();
+
+    for image in images {
+        let index = match groups.iter().position(|group| group.leads == image.leads) {
+            Some(index) => {
+                groups[index].combine(&image);
+                index
+            }
+            None => {
+                groups.push(Accumulator::new(image));
+                groups.len() - 1
+            }
+        };
+
+        if groups
+            .iter()
+            .any(|group| group.primes > groups[index].primes)
+        {
+            continue;
+        }
+
+        let candidate = groups[index].reconstruct();
+        if let Some(candidate) = candidate
+            .as_ref()
+            .filter(|&c| Some(c) == groups[index].previous.as_ref())
+        {
+            if verify(candidate, polys) {
+                return candidate
+                    .iter()
+                    .map(|monomials| BigRationalPolynomial {
+                        monomials: monomials.clone(),
+                    })
+                    .collect();
+            }
+        }
+        groups[index].previous = candidate;
+    }
+    unreachable!("There are infinitely many primes")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{order::Lex, variable::Var};
+
+    #[test]
+    fn test_groebner_over_q() {
+        let generators: Vec<Polynomial<Rational, Var, Lex>> = vec![
+            Polynomial::from_str("37x^2+29y^2+-31").unwrap(),
+            Polynomial::from_str("17xy+-19x+23y+-41").unwrap(),
+        ];
+
+        let basis: Vec<Polynomial<Rational, Var, Lex>> = groebner_over_q(&generators)
+            .iter()
+            .map(|g| g.to_rational().unwrap())
+            .collect();
+
+        assert_eq!(basis, reduced_basis(&buchberger(&generators)));
+        assert!(basis
+            .iter()
+            .flat_map(|g| g.monomials.values())
+            .any(|c| c.denom() != 1));
+
+        // the coefficients need several primes to be reconstructed
+        let generators: Vec<Polynomial<Rational, Var, Lex>> = vec![
+            Polynomial::from_str("1000003x+-123456789").unwrap(),
+            Polynomial::from_str("y^2+-x").unwrap(),
+        ];
+        let basis = groebner_over_q(&generators);
+        let expected: Vec<BigRationalPolynomial<Var, Lex>> =
+            reduced_basis(&buchberger(&generators))
+                .iter()
+                .map(BigRationalPolynomial::from)
+                .collect();
+        assert_eq!(basis, expected);
+
+        // y = 1 / 4294967311^2 has a denominator beyond i64
+        let generators: Vec<Polynomial<Rational, Var, Lex>> = vec![
+            Polynomial::from_str("4294967311x+-1").unwrap(),
+            Polynomial::from_str("y+-x^2").unwrap(),
+        ];
+        let basis = groebner_over_q(&generators);
+        let a = BigInt::from(4294967311u64);
+        let y = basis.iter().find(|g| g.to_rational().is_none()).unwrap();
+        assert!(y
+            .iter()
+            .any(|(_, c)| *c == -BigRational::new(BigInt::one(), &a * &a)));
+    }
+
+    #[test]
+    fn test_rational_reconstruction() {
+        let p = BigInt::from(2147483647u64);
+        let a = BigInt::from(2147483647 - 22) * BigInt::from(inv_mod(7, 2147483647));
+        assert_eq!(
+            rational_reconstruction(&(a % &p), &p),
+            Some(BigRational::new((-22).into(), 7.into()))
+        );
+        // too large to be recovered modulo a single prime
+        let m = &p * BigInt::from(2147483629u64);
+        let a = BigInt::from(3784172138265480781u64);
+        assert_eq!(
+            rational_reconstruction(&a, &m),
+            Some(BigRational::new((-123456789).into(), 1000003.into()))
+        );
+        assert_eq!(rational_reconstruction(&(&a % &p), &p), None);
+
+        // no fraction with small numerator and denominator in this class
+        assert_eq!(rational_reconstruction(&BigInt::from(1234567890), &p), None);
+    }
+}
@@ -0,0 +1,396 @@
+use num::{One, Zero};
+
+use crate::{
+    monomial::Monomial,
+    order::{Lex, ModuleOrder, Order, PositionOverTerm},
+    polynomial::{buchberger_loop, sub_mono_poly_assign, HeadMonomial, Polynomial},
+    ring::{Field, Ring},
+    term::{lcm, Term},
+    variable::{Var, Variable},
+};
+
+/// The lead position, term and coefficient of a module element.
+pub type ModuleLead<R, V> = (usize, Term<V>, R);
+
+/// An element of the free module `R[x]^rank`, a vector of polynomials.
+#[derive(Debug)]
+pub struct ModuleElement<R: Ring = i32, V: Variable = Var, O: Order = Lex> {
+    components: Vec<Polynomial<R, V, O>>,
+}
+
+impl<R: Ring, V: Variable, O: Order> Clone for ModuleElement<R, V, O> {
+    fn clone(&self) -> Self {
+        Self {
+            components: self.components.clone(),
+        }
+    }
+}
+
+impl<R: Ring, V: Variable, O: Order> PartialEq for ModuleElement<R, V, O> {
+    fn eq(&self, other: &Self) -> bool {
+        self.components == other.components
+    }
+}
+
+impl<R: Ring, V: Variable, O: Order> Eq for ModuleElement<R, V, O> {}
+
+impl<R: Ring, V: Variable, O: Order> ModuleElement<R, V, O> {
+    pub fn new(components: Vec<Polynomial<R, V, O>>) -> Self {
+        Self { components }
+    }
+
+    /// The zero vector of the free module of rank `rank`.
+    pub fn zero(rank: usize) -> Self {
+        Self::new(vec![Polynomial::zero(); rank])
+    }
+
+    /// The `i`-th standard basis vector `e_i` of the free module of rank
+    /// `rank`.
+    pub fn unit(rank: usize, i: usize) -> Self {
+        let mut element = Self::zero(rank);
+        element.components[i] = Polynomial::one();
+        element
+    }
+
+    pub fn rank(&self) -> usize {
+        self.components.len()
+    }
+
+    pub fn components(&self) -> &[Polynomial<R, V, O>] {
+        &self.components
+    }
+
+    pub fn into_components(self) -> Vec<Polynomial<R, V, O>> {
+        self.components
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.components.iter().all(|f| f.is_zero())
+    }
+
+    /// The lead position, term and coefficient for the module order `M`, none
+    /// for the zero vector.
+    pub fn lead<M: ModuleOrder>(&self) -> Option<ModuleLead<R, V>> {
+        self.components
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| !f.is_zero())
+            .map(|(i, f)| (i, f.lead_term(), f.lead_coeff()))
+            .max_by(|left, right| M::cmp::<V, O>((left.0, &left.1), (right.0, &right.1)))
+    }
+
+    /// The dot product `sum(self[i] * polys[i])`, the image of `self` under
+    /// the map `e_i -> polys[i]`.
+    pub fn dot(&self, polys: &[Polynomial<R, V, O>]) -> Polynomial<R, V, O> {
+        assert_eq!(self.rank(), polys.len(), "The ranks must match");
+        self.components
+            .iter()
+            .zip(polys.iter())
+            .fold(Polynomial::zero(), |acc, (a, f)| acc + a * f)
+    }
+}
+
+impl<R: Ring, V: Variable, O: Order> std::ops::Index<usize> for ModuleElement<R, V, O> {
+    type Output = Polynomial<R, V, O>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.components[index]
+    }
+}
+
+impl<R: Ring, V: Variable, O: Order> std::fmt::Display for ModuleElement<R, V, O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        for (i, component) in self.components.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", component)?;
+        }
+        write!(f, "]")
+    }
+}
+
+#[inline]
+pub(crate) fn add_mod_mod<R: Ring, V: Variable, O: Order>(
+    left: &ModuleElement<R, V, O>,
+    right: &ModuleElement<R, V, O>,
+) -> ModuleElement<R, V, O> {
+    assert_eq!(left.rank(), right.rank(), "The ranks must match");
+    ModuleElement::new(
+        left.components
+            .iter()
+            .zip(right.components.iter())
+            .map(|(a, b)| a + b)
+            .collect(),
+    )
+}
+
+#[inline]
+pub(crate) fn sub_mod_mod<R: Ring, V: Variable, O: Order>(
+    left: &ModuleElement<R, V, O>,
+    right: &ModuleElement<R, V, O>,
+) -> ModuleElement<R, V, O> {
+    assert_eq!(left.rank(), right.rank(), "The ranks must match");
+    ModuleElement::new(
+        left.components
+            .iter()
+            .zip(right.components.iter())
+            .map(|(a, b)| a - b)
+            .collect(),
+    )
+}
+
+#[inline]
+pub(crate) fn mul_poly_mod<R: Ring, V: Variable, O: Order>(
+    left: &Polynomial<R, V, O>,
+    right: &ModuleElement<R, V, O>,
+) -> ModuleElement<R, V, O> {
+    ModuleElement::new(right.components.iter().map(|a| left * a).collect())
+}
+
+/// The element `mono * f`.
+fn mul_mono_mod<R: Ring, V: Variable, O: Order>(
+    mono: Monomial<R, V>,
+    f: &ModuleElement<R, V, O>,
+) -> ModuleElement<R, V, O> {
+    ModuleElement::new(f.components.iter().map(|a| a * &mono).collect())
+}
+
+/// `f` divided by its lead coefficient.
+fn monic_mod<R: Field, V: Variable, O: Order, M: ModuleOrder>(
+    f: &ModuleElement<R, V, O>,
+) -> ModuleElement<R, V, O> {
+    match f.lead::<M>() {
+        Some((_, _, coeff)) => mul_mono_mod(Term::default() * coeff.inv(), f),
+        None => f.clone(),
+    }
+}
+
+/// The S-vector of `f` and `g` for the module order `M`, none when their lead
+/// terms lie in different positions.
+pub fn svector<R: Field, V: Variable, O: Order, M: ModuleOrder>(
+    f: &ModuleElement<R, V, O>,
+    g: &ModuleElement<R, V, O>,
+) -> Option<ModuleElement<R, V, O>> {
+    let (i, f_term, f_coeff) = f.lead::<M>()?;
+    let (j, g_term, g_coeff) = g.lead::<M>()?;
+    if i != j {
+        return None;
+    }
+
+    let m = lcm(&f_term, &g_term);
+    Some(
+        mul_mono_mod((&m / &f_term).unwrap() * f_coeff.inv(), f)
+            - mul_mono_mod((&m / &g_term).unwrap() * g_coeff.inv(), g),
+    )
+}
+
+/// Fully reduce `f` by `basis` for the module order `M`, no term of the
+/// result is divisible by a lead term of `basis` in the same position.
+pub fn module_normal_form<R: Field, V: Variable, O: Order, M: ModuleOrder>(
+    f: &ModuleElement<R, V, O>,
+    basis: &[ModuleElement<R, V, O>],
+) -> ModuleElement<R, V, O> {
+    let leads: Vec<_> = basis
+        .iter()
+        .filter_map(|g| g.lead::<M>().map(|lead| (g, lead)))
+        .collect();
+    let mut f = f.clone();
+    let mut remainder = ModuleElement::zero(f.rank());
+
+    while let Some((i, term, coeff)) = f.lead::<M>() {
+        let divisor = leads.iter().find_map(|(g, (j, g_term, g_coeff))| {
+            (i == *j)
+                .then(|| &term / g_term)
+                .flatten()
+                .map(|m| (m * (coeff / *g_coeff), g))
+        });
+
+        if let Some((mono, g)) = divisor {
+            for (a, b) in f.components.iter_mut().zip(g.components.iter()) {
+                sub_mono_poly_assign(a, &mono, b);
+            }
+        } else {
+            let (term, coeff) = f.components[i].monomials.pop_last().unwrap();
+            remainder.components[i].monomials.insert(term, coeff);
+        }
+    }
+    remainder
+}
+
+/// Gröbner basis of the submodule generated by `generators` for the module
+/// order `M`, reduced as [`reduced_module_basis`].
+pub fn module_buchberger<R: Field, V: Variable, O: Order, M: ModuleOrder>(
+    generators: &[ModuleElement<R, V, O>],
+) -> Vec<ModuleElement<R, V, O>> {
+    let mut basis: Vec<ModuleElement<R, V, O>> = generators
+        .iter()
+        .filter(|f| !f.is_zero())
+        .cloned()
+        .collect();
+
+    buchberger_loop(&mut basis, |basis, i, j| {
+        let s = svector::<R, V, O, M>(&basis[i], &basis[j])?;
+        let remainder = module_normal_form::<R, V, O, M>(&s, basis);
+        (!remainder.is_zero()).then_some(remainder)
+    });
+    reduced_module_basis::<R, V, O, M>(&basis)
+}
+
+/// Turn a Gröbner basis of a submodule into its unique reduced Gröbner basis:
+/// monic, with no term divisible by a lead term of another element in the
+/// same position, sorted by decreasing lead terms.
+pub fn reduced_module_basis<R: Field, V: Variable, O: Order, M: ModuleOrder>(
+    basis: &[ModuleElement<R, V, O>],
+) -> Vec<ModuleElement<R, V, O>> {
+    let leads: Vec<Option<ModuleLead<R, V>>> = basis.iter().map(|g| g.lead::<M>()).collect();
+    let divides =
+        |(i, t, _): &ModuleLead<R, V>, (j, u, _): &ModuleLead<R, V>| i == j && (u / t).is_some();
+
+    let minimal: Vec<ModuleElement<R, V, O>> = leads
+        .iter()
+        .enumerate()
+        .filter_map(|(k, lead)| lead.as_ref().map(|lead| (k, lead)))
+        .filter(|&(k, lead)| {
+            !leads.iter().enumerate().any(|(l, other)| {
+                other.as_ref().is_some_and(|other| {
+                    l != k && divides(other, lead) && (other.1 != lead.1 || l < k)
+                })
+            })
+        })
+        .map(|(k, _)| monic_mod::<R, V, O, M>(&basis[k]))
+        .collect();
+
+    let mut reduced: Vec<ModuleElement<R, V, O>> = (0..minimal.len())
+        .map(|k| {
+            let others: Vec<ModuleElement<R, V, O>> = minimal
+                .iter()
+                .enumerate()
+                .filter(|&(l, _)| l != k)
+                .map(|(_, g)| g.clone())
+                .collect();
+            module_normal_form::<R, V, O, M>(&minimal[k], &others)
+        })
+        .collect();
+    reduced.sort_by(|left, right| {
+        let (left, right) = (left.lead::<M>().unwrap(), right.lead::<M>().unwrap());
+        M::cmp::<V, O>((left.0, &left.1), (right.0, &right.1)).reverse()
+    });
+    reduced
+}
+
+/// Generators of the kernel of the map `R[x]^n -> R[x]^m` sending `e_j` to
+/// `columns[j]`, that is the syzygies of `columns`, which present the
+/// submodule they generate.
+///
+/// The kernel is read off a [`PositionOverTerm`] basis of the elements
+/// `(columns[j], e_j)` of `R[x]^(m+n)`, as the elements vanishing in the
+/// first `m` positions. The result is its reduced Gröbner basis for
+/// [`PositionOverTerm`].
+pub fn kernel<R: Field, V: Variable, O: Order>(
+    columns: &[ModuleElement<R, V, O>],
+) -> Vec<ModuleElement<R, V, O>> {
+    let Some(m) = columns.first().map(|c| c.rank()) else {
+        return Default::default();
+    };
+    let n = columns.len();
+    assert!(
+        columns.iter().all(|c| c.rank() == m),
+        "The columns must have the same rank"
+    );
+
+    let extended: Vec<ModuleElement<R, V, O>> = columns
+        .iter()
+        .enumerate()
+        .map(|(j, c)| {
+            let mut components = c.components.clone();
+            components.extend(ModuleElement::unit(n, j).components);
+            ModuleElement::new(components)
+        })
+        .collect();
+
+    module_buchberger::<R, V, O, PositionOverTerm>(&extended)
+        .into_iter()
+        .filter(|g| g.components[..m].iter().all(|f| f.is_zero()))
+        .map(|g| ModuleElement::new(g.components[m..].to_vec()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{order::TermOverPosition, ring::Rational};
+
+    #[test]
+    fn test_module_element() {
+        let f: Polynomial<i32, Var> = Polynomial::from_str("xy+-1").unwrap();
+        let u = ModuleElement::new(vec![Polynomial::from_str("x").unwrap(), f.clone()]);
+        let v = ModuleElement::unit(2, 1);
+
+        assert_eq!(
+            &u + &v,
+            ModuleElement::new(vec![
+                Polynomial::from_str("x").unwrap(),
+                Polynomial::from_str("xy").unwrap()
+            ])
+        );
+        assert!((&u - &u).is_zero());
+        assert_eq!(
+            &f * &v,
+            ModuleElement::new(vec![Polynomial::zero(), f.clone()])
+        );
+        assert_eq!(
+            u.dot(&[Polynomial::from_str("y").unwrap(), Polynomial::one()]),
+            Polynomial::from_str("2xy+-1").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_kernel() {
+        let column = |a: &str, b: &str| -> ModuleElement<Rational, Var> {
+            ModuleElement::new(vec![
+                Polynomial::from_str(a).unwrap(),
+                Polynomial::from_str(b).unwrap(),
+            ])
+        };
+        let row = |a: &str| -> ModuleElement<Rational, Var> {
+            ModuleElement::new(vec![Polynomial::from_str(a).unwrap()])
+        };
+
+        // the Koszul syzygy of x and y
+        assert_eq!(kernel(&[row("x"), row("y")]), vec![column("y", "-1x")]);
+        // the second column is x times the first
+        assert_eq!(
+            kernel(&[column("x", "y"), column("x^2", "xy")]),
+            vec![column("x", "-1")]
+        );
+    }
+
+    #[test]
+    fn test_module_buchberger() {
+        let generators: Vec<ModuleElement<Rational, Var>> = vec![
+            ModuleElement::new(vec![
+                Polynomial::from_str("x").unwrap(),
+                Polynomial::from_str("y").unwrap(),
+            ]),
+            ModuleElement::new(vec![
+                Polynomial::from_str("y").unwrap(),
+                Polynomial::from_str("x").unwrap(),
+            ]),
+        ];
+        let pot = module_buchberger::<_, _, _, PositionOverTerm>(&generators);
+        let top = module_buchberger::<_, _, _, TermOverPosition>(&generators);
+        assert_ne!(pot, top);
+
+        // both generate the same submodule
+        for f in generators.iter().chain(top.iter()) {
+            assert!(module_normal_form::<_, _, _, PositionOverTerm>(f, &pot).is_zero());
+        }
+        for f in generators.iter().chain(pot.iter()) {
+            assert!(module_normal_form::<_, _, _, TermOverPosition>(f, &top).is_zero());
+        }
+    }
+}
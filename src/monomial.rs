@@ -7,21 +7,23 @@ pub struct Monomial<R: Ring, V: Variable> {
 }
 
 use crate::variable::Variable;
-use crate::{ring::Ring, term::Term};
+use crate::{
+    ring::{pow_ring, Ring},
+    term::{pow_term, Term},
+};
 
+#[doc(hidden)]
 #[inline]
-pub(crate) fn mul_ring_term<R: Ring, V: Variable>(left: R, right: Term<V>) -> Monomial<R, V> {
+pub fn mul_ring_term<R: Ring, V: Variable>(left: R, right: Term<V>) -> Monomial<R, V> {
     Monomial {
         coeff: left,
         term: right,
     }
 }
 
+#[doc(hidden)]
 #[inline]
-pub(crate) fn mul_ring_mono<R: Ring, V: Variable>(
-    left: R,
-    right: Monomial<R, V>,
-) -> Monomial<R, V> {
+pub fn mul_ring_mono<R: Ring, V: Variable>(left: R, right: Monomial<R, V>) -> Monomial<R, V> {
     Monomial {
         coeff: left * right.coeff,
         term: right.term,
@@ -49,3 +51,11 @@ pub(crate) fn mul_mono_mono<R: Ring, V: Variable>(
         term: &left.term * &right.term,
     }
 }
+
+#[inline]
+pub(crate) fn pow_mono<R: Ring, V: Variable>(mono: &Monomial<R, V>, exp: u32) -> Monomial<R, V> {
+    Monomial {
+        coeff: pow_ring(mono.coeff, exp as usize),
+        term: pow_term(&mono.term, exp),
+    }
+}
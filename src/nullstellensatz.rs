@@ -0,0 +1,156 @@
+use std::collections::BTreeMap;
+
+use num::{One, Zero};
+
+use crate::{
+    lift::lift,
+    module::ModuleElement,
+    order::{Order, OrderedTerm},
+    polynomial::{HeadMonomial, Polynomial},
+    ring::{Field, Ring},
+    term::{Degree, Term},
+    variable::Variable,
+};
+
+/// Certificate `h` of the infeasibility of `generators`, with
+/// `1 = sum(h[i] * generators[i])`, or none when the system has solutions in
+/// the algebraic closure.
+///
+/// The certificate is lifted through the transformation matrix of a
+/// Gröbner basis, see [`lift`].
+pub fn nullstellensatz_certificate<R: Field, V: Variable, O: Order>(
+    generators: &[Polynomial<R, V, O>],
+) -> Option<Vec<Polynomial<R, V, O>>> {
+    lift(&Polynomial::one(), generators)
+}
+
+/// Whether `1 = sum(certificate[i] * generators[i])`, checked with plain
+/// multiplications and additions.
+pub fn verify_certificate<R: Ring, V: Variable, O: Order>(
+    certificate: &[Polynomial<R, V, O>],
+    generators: &[Polynomial<R, V, O>],
+) -> bool {
+    certificate.len() == generators.len()
+        && certificate
+            .iter()
+            .zip(generators.iter())
+            .fold(Polynomial::zero(), |acc, (h, f)| acc + h * f)
+            .is_one()
+}
+
+/// All the terms in `variables` of degree at most `degree`.
+fn terms_up_to<V: Variable>(variables: &[V], degree: usize) -> Vec<Term<V>> {
+    let Some((&var, rest)) = variables.split_first() else {
+        return vec![Term::default()];
+    };
+    (0..=degree)
+        .flat_map(|exp| {
+            terms_up_to(rest, degree - exp)
+                .into_iter()
+                .map(move |t| t * Term::from_iter([(var, exp)]))
+        })
+        .collect()
+}
+
+/// Polynomials in echelon form by their lead terms, each with its
+/// representation in terms of the generators.
+type EchelonRows<R, V, O> =
+    BTreeMap<OrderedTerm<V, O>, (Polynomial<R, V, O>, ModuleElement<R, V, O>)>;
+
+/// Cancel the lead terms of `f` against `rows` for as long as possible,
+/// subtracting the same multiples of the representations from `repr`.
+fn reduce_rows<R: Field, V: Variable, O: Order>(
+    rows: &EchelonRows<R, V, O>,
+    mut f: Polynomial<R, V, O>,
+    mut repr: ModuleElement<R, V, O>,
+) -> (Polynomial<R, V, O>, ModuleElement<R, V, O>) {
+    while let Some((p, r)) = f.monomials.last_key_value().and_then(|(t, _)| rows.get(t)) {
+        let c: Polynomial<R, V, O> = [Term::default() * (f.lead_coeff() / p.lead_coeff())]
+            .into_iter()
+            .collect();
+        f = f - &c * p;
+        repr = repr - &c * r;
+    }
+    (f, repr)
+}
+
+/// Certificate of infeasibility with `deg(h[i] * generators[i]) <= degree`,
+/// found by linear algebra without a Gröbner basis, or none when no such
+/// certificate exists.
+///
+/// The products `t * generators[i]` are put in echelon form by their lead
+/// terms, keeping track of the combinations, and `1` is reduced by the
+/// result. Larger bounds find more certificates, at the cost of a linear
+/// system with as many unknowns as such products.
+pub fn bounded_certificate<R: Field, V: Variable, O: Order>(
+    generators: &[Polynomial<R, V, O>],
+    degree: usize,
+) -> Option<Vec<Polynomial<R, V, O>>> {
+    let mut variables: Vec<V> = generators
+        .iter()
+        .flat_map(|f| f.iter())
+        .flat_map(|m| m.term.exps.into_iter().map(|(v, _)| v))
+        .collect();
+    variables.sort();
+    variables.dedup();
+
+    let rank = generators.len();
+    let mut rows: EchelonRows<R, V, O> = Default::default();
+    for (i, f) in generators.iter().enumerate() {
+        if f.is_zero() || f.deg() > degree {
+            continue;
+        }
+        for t in terms_up_to(&variables, degree - f.deg()) {
+            let product = f * &t;
+            let repr = &Polynomial::from_iter([t * R::one()]) * &ModuleElement::unit(rank, i);
+            let (product, repr) = reduce_rows(&rows, product, repr);
+            if !product.is_zero() {
+                rows.insert(product.lead_term().into(), (product, repr));
+            }
+        }
+    }
+
+    // the reduction keeps 1 = f - repr * generators
+    let (one, repr) = reduce_rows(&rows, Polynomial::one(), ModuleElement::zero(rank));
+    one.is_zero()
+        .then(|| (ModuleElement::zero(rank) - repr).into_components())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{ring::Rational, variable::Var};
+
+    #[test]
+    fn test_nullstellensatz_certificate() {
+        let generators: Vec<Polynomial<Rational, Var>> = vec![
+            Polynomial::from_str("x^2").unwrap(),
+            Polynomial::from_str("xy+-1").unwrap(),
+        ];
+        let certificate = nullstellensatz_certificate(&generators).unwrap();
+        assert!(verify_certificate(&certificate, &generators));
+
+        // the circle meets the line x = y
+        let feasible: Vec<Polynomial<Rational, Var>> = vec![
+            Polynomial::from_str("x^2+y^2+-1").unwrap(),
+            Polynomial::from_str("x+-y").unwrap(),
+        ];
+        assert!(nullstellensatz_certificate(&feasible).is_none());
+    }
+
+    #[test]
+    fn test_bounded_certificate() {
+        // 1 = y^2 * x^2 - (xy + 1)(xy - 1) needs degree 4
+        let generators: Vec<Polynomial<Rational, Var>> = vec![
+            Polynomial::from_str("x^2").unwrap(),
+            Polynomial::from_str("xy+-1").unwrap(),
+        ];
+        assert!(bounded_certificate(&generators, 3).is_none());
+
+        let certificate = bounded_certificate(&generators, 4).unwrap();
+        assert!(verify_certificate(&certificate, &generators));
+        assert!(!verify_certificate(&certificate[..1], &generators[..1]));
+    }
+}
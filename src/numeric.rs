@@ -0,0 +1,391 @@
+use num::{complex::Complex64, ToPrimitive, Zero};
+
+use crate::{
+    matrix::Matrix, order::Order, polynomial::Polynomial, ring::Field, variable::Variable,
+    zero_dim::multiplication_matrices,
+};
+
+type ComplexMatrix = Vec<Vec<Complex64>>;
+
+/// An approximate solution of a polynomial system.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumericalSolution {
+    coordinates: Vec<Complex64>,
+    residual: f64,
+}
+
+impl NumericalSolution {
+    /// Coordinates in the order of the variables given to the solver.
+    pub fn coordinates(&self) -> &[Complex64] {
+        &self.coordinates
+    }
+
+    /// Largest absolute value of the input polynomials at the solution.
+    pub fn residual(&self) -> f64 {
+        self.residual
+    }
+}
+
+fn to_complex<R: Field + ToPrimitive>(matrix: &Matrix<R>) -> ComplexMatrix {
+    (0..matrix.rows())
+        .map(|i| {
+            matrix
+                .row(i)
+                .iter()
+                .map(|c| Complex64::new(c.to_f64().unwrap_or(f64::NAN), 0.0))
+                .collect()
+        })
+        .collect()
+}
+
+/// Similarity transformation to upper Hessenberg form with Householder
+/// reflections.
+fn hessenberg(mut a: ComplexMatrix) -> ComplexMatrix {
+    let n = a.len();
+    for k in 0..n.saturating_sub(2) {
+        let norm = (k + 1..n).map(|i| a[i][k].norm_sqr()).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+        let phase = if a[k + 1][k].norm() == 0.0 {
+            Complex64::new(1.0, 0.0)
+        } else {
+            a[k + 1][k] / a[k + 1][k].norm()
+        };
+
+        let mut v: Vec<Complex64> = (k + 1..n).map(|i| a[i][k]).collect();
+        v[0] += phase * norm;
+        let v_norm = v.iter().map(|x| x.norm_sqr()).sum::<f64>().sqrt();
+        v.iter_mut().for_each(|x| *x /= v_norm);
+
+        // A <- (I - 2 v v^H) A (I - 2 v v^H)
+        for j in 0..n {
+            let dot: Complex64 = a[k + 1..]
+                .iter()
+                .zip(v.iter())
+                .map(|(row, x)| x.conj() * row[j])
+                .sum();
+            for (row, x) in a[k + 1..].iter_mut().zip(v.iter()) {
+                row[j] -= x * dot * 2.0;
+            }
+        }
+        for row in a.iter_mut() {
+            let dot: Complex64 = (k + 1..n).map(|j| row[j] * v[j - k - 1]).sum();
+            for j in k + 1..n {
+                row[j] -= dot * v[j - k - 1].conj() * 2.0;
+            }
+        }
+    }
+    a
+}
+
+/// Rotation `(c, s)` with a real `c`, sending `(a, b)` to `(r, 0)`.
+fn givens(a: Complex64, b: Complex64) -> (f64, Complex64) {
+    let r = (a.norm_sqr() + b.norm_sqr()).sqrt();
+    if r == 0.0 {
+        (1.0, Complex64::zero())
+    } else if a.norm() == 0.0 {
+        (0.0, b.conj() / b.norm())
+    } else {
+        let phase = a / a.norm();
+        (a.norm() / r, phase * b.conj() / r)
+    }
+}
+
+/// Eigenvalue of a 2x2 matrix closest to its bottom right entry.
+fn wilkinson_shift(a: Complex64, b: Complex64, c: Complex64, d: Complex64) -> Complex64 {
+    let half = (a - d) / 2.0;
+    let disc = (half * half + b * c).sqrt();
+    let (first, second) = ((a + d) / 2.0 + disc, (a + d) / 2.0 - disc);
+    if (first - d).norm() < (second - d).norm() {
+        first
+    } else {
+        second
+    }
+}
+
+/// Eigenvalues with the shifted QR algorithm on the Hessenberg form, or none
+/// when an eigenvalue does not converge within `100 * n` iterations.
+fn eigenvalues(a: &ComplexMatrix) -> Option<Vec<Complex64>> {
+    let mut h = hessenberg(a.clone());
+    let n = h.len();
+    let mut values: Vec<Complex64> = Default::default();
+    if n == 0 {
+        return Some(values);
+    }
+
+    let mut hi = n - 1;
+    let mut iterations = 0;
+    while hi > 0 {
+        let lo = (1..=hi)
+            .rev()
+            .find(|&k| {
+                let scale = h[k][k].norm() + h[k - 1][k - 1].norm();
+                h[k][k - 1].norm() <= f64::EPSILON * scale.max(f64::MIN_POSITIVE)
+            })
+            .unwrap_or(0);
+
+        if iterations > 100 * n {
+            return None;
+        }
+        if lo == hi {
+            values.push(h[hi][hi]);
+            hi -= 1;
+            iterations = 0;
+            continue;
+        }
+        iterations += 1;
+
+        let mut shift = wilkinson_shift(h[hi - 1][hi - 1], h[hi - 1][hi], h[hi][hi - 1], h[hi][hi]);
+        // an exceptional shift breaks the cycles of the plain Wilkinson shift
+        if iterations % 10 == 0 {
+            shift += h[hi][hi - 1].norm();
+        }
+
+        for (k, row) in h.iter_mut().enumerate().take(hi + 1).skip(lo) {
+            row[k] -= shift;
+        }
+        let mut rotations: Vec<(f64, Complex64)> = Default::default();
+        for k in lo..hi {
+            let (c, s) = givens(h[k][k], h[k + 1][k]);
+            let (top, bottom) = h.split_at_mut(k + 1);
+            for (x, y) in top[k][k..=hi].iter_mut().zip(bottom[0][k..=hi].iter_mut()) {
+                (*x, *y) = (*x * c + s * *y, -s.conj() * *x + *y * c);
+            }
+            rotations.push((c, s));
+        }
+        for (k, &(c, s)) in (lo..hi).zip(rotations.iter()) {
+            for row in h[lo..=(k + 2).min(hi)].iter_mut() {
+                let (x, y) = (row[k], row[k + 1]);
+                row[k] = x * c + y * s.conj();
+                row[k + 1] = -x * s + y * c;
+            }
+        }
+        for (k, row) in h.iter_mut().enumerate().take(hi + 1).skip(lo) {
+            row[k] += shift;
+        }
+    }
+    values.push(h[0][0]);
+    Some(values)
+}
+
+/// Solve `a x = b` with Gaussian elimination and partial pivoting.
+fn solve_linear(mut a: ComplexMatrix, mut b: Vec<Complex64>) -> Vec<Complex64> {
+    let n = a.len();
+    for k in 0..n {
+        let pivot = (k..n)
+            .max_by(|&i, &j| a[i][k].norm().total_cmp(&a[j][k].norm()))
+            .unwrap();
+        a.swap(k, pivot);
+        b.swap(k, pivot);
+        // a singular pivot is nudged, which is the point of inverse iteration
+        if a[k][k].norm() < f64::EPSILON {
+            a[k][k] = Complex64::new(f64::EPSILON, 0.0);
+        }
+        let (top, bottom) = a.split_at_mut(k + 1);
+        let (b_top, b_bottom) = b.split_at_mut(k + 1);
+        let pivot_row = &top[k];
+        for (row, value) in bottom.iter_mut().zip(b_bottom.iter_mut()) {
+            let factor = row[k] / pivot_row[k];
+            for (x, y) in row[k..].iter_mut().zip(pivot_row[k..].iter()) {
+                *x -= factor * y;
+            }
+            *value -= factor * b_top[k];
+        }
+    }
+
+    let mut x = vec![Complex64::zero(); n];
+    for i in (0..n).rev() {
+        let sum: Complex64 = (i + 1..n).map(|j| a[i][j] * x[j]).sum();
+        x[i] = (b[i] - sum) / a[i][i];
+    }
+    x
+}
+
+/// Eigenvector for an approximate eigenvalue, with inverse iteration.
+fn eigenvector(a: &ComplexMatrix, value: Complex64) -> Vec<Complex64> {
+    let n = a.len();
+    let shifted: ComplexMatrix = a
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut row = row.clone();
+            row[i] -= value;
+            row
+        })
+        .collect();
+
+    let mut x = vec![Complex64::new(1.0, 0.0); n];
+    for _ in 0..3 {
+        x = solve_linear(shifted.clone(), x);
+        let norm = x.iter().map(|c| c.norm_sqr()).sum::<f64>().sqrt();
+        x.iter_mut().for_each(|c| *c /= norm);
+    }
+    x
+}
+
+/// `v^H a v / v^H v`, the eigenvalue of `a` when `v` is an eigenvector.
+fn rayleigh_quotient(a: &ComplexMatrix, v: &[Complex64]) -> Complex64 {
+    let av: Vec<Complex64> = a
+        .iter()
+        .map(|row| row.iter().zip(v).map(|(x, y)| x * y).sum())
+        .collect();
+    let numerator: Complex64 = v.iter().zip(av.iter()).map(|(x, y)| x.conj() * y).sum();
+    let denominator: f64 = v.iter().map(|x| x.norm_sqr()).sum();
+    numerator / denominator
+}
+
+fn eval_complex<R: Field + ToPrimitive, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    variables: &[V],
+    point: &[Complex64],
+) -> Complex64 {
+    f.iter()
+        .map(|m| {
+            m.term.exps.iter().fold(
+                Complex64::new(m.coeff.to_f64().unwrap_or(f64::NAN), 0.0),
+                |acc, &(var, exp)| {
+                    let x = variables
+                        .iter()
+                        .position(|&v| v == var)
+                        .map_or(Complex64::zero(), |i| point[i]);
+                    acc * x.powu(exp as u32)
+                },
+            )
+        })
+        .sum()
+}
+
+/// Approximate complex solutions of a zero-dimensional system, from the
+/// eigenvectors of its multiplication matrices.
+///
+/// The transposed multiplication matrices, built from the Gröbner basis
+/// `basis` and its standard monomials, share the evaluation vectors at the
+/// solutions as eigenvectors. Eigenvalues of a fixed generic combination of
+/// them are computed with the shifted QR algorithm, eigenvectors with inverse
+/// iteration, and every coordinate is read off as the Rayleigh quotient of
+/// the matrix of its variable. Eigenvalues closer than `1e-6` are merged, so
+/// a multiple solution is reported once, although with a lower accuracy.
+///
+/// Coordinates follow the order of `variables`, and residuals are evaluated
+/// on `polys`, usually the original generators. Returns `None` when the ideal
+/// is not zero-dimensional, or when the QR algorithm does not converge.
+pub fn approximate_solutions<R, V: Variable, O: Order>(
+    basis: &[Polynomial<R, V, O>],
+    variables: &[V],
+    polys: &[Polynomial<R, V, O>],
+) -> Option<Vec<NumericalSolution>>
+where
+    R: Field + ToPrimitive,
+{
+    let matrices: Vec<ComplexMatrix> = multiplication_matrices(basis, variables)?
+        .iter()
+        .map(|m| to_complex(&m.transpose()))
+        .collect();
+    let n = matrices.first().map_or(0, |m| m.len());
+
+    // square roots of distinct integers are unlikely to align with the
+    // solutions
+    let mut combination: ComplexMatrix = vec![vec![Complex64::zero(); n]; n];
+    for (k, matrix) in matrices.iter().enumerate() {
+        let weight = ((k + 2) as f64).sqrt();
+        for (row, matrix_row) in combination.iter_mut().zip(matrix.iter()) {
+            for (x, y) in row.iter_mut().zip(matrix_row.iter()) {
+                *x += y * weight;
+            }
+        }
+    }
+
+    let mut values: Vec<Complex64> = Default::default();
+    for value in eigenvalues(&combination)? {
+        if values
+            .iter()
+            .all(|other| (other - value).norm() > 1e-6 * value.norm().max(1.0))
+        {
+            values.push(value);
+        }
+    }
+
+    let solutions = values
+        .iter()
+        .map(|&value| {
+            let v = eigenvector(&combination, value);
+            let coordinates: Vec<Complex64> = matrices
+                .iter()
+                .map(|matrix| rayleigh_quotient(matrix, &v))
+                .collect();
+            let residual = polys
+                .iter()
+                .map(|f| eval_complex(f, variables, &coordinates).norm())
+                .fold(0.0, f64::max);
+            NumericalSolution {
+                coordinates,
+                residual,
+            }
+        })
+        .collect();
+    Some(solutions)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        order::GradLex,
+        polynomial::{buchberger, reduced_basis},
+        ring::Rational,
+        var,
+        variable::Var,
+    };
+
+    fn solve(generators: &[&str]) -> Vec<NumericalSolution> {
+        let generators: Vec<Polynomial<Rational, Var, GradLex>> = generators
+            .iter()
+            .map(|s| Polynomial::from_str(s).unwrap())
+            .collect();
+        let basis = reduced_basis(&buchberger(&generators));
+        approximate_solutions(&basis, &[var!(x), var!(y)], &generators).unwrap()
+    }
+
+    fn contains(solutions: &[NumericalSolution], expected: [Complex64; 2]) -> bool {
+        solutions.iter().any(|solution| {
+            solution
+                .coordinates()
+                .iter()
+                .zip(expected.iter())
+                .all(|(x, y)| (x - y).norm() < 1e-8)
+        })
+    }
+
+    #[test]
+    fn test_real_solutions() {
+        let solutions = solve(&["x^2+y^2+-5", "xy+-2"]);
+
+        assert_eq!(solutions.len(), 4);
+        assert!(solutions.iter().all(|solution| solution.residual() < 1e-8));
+        for (x, y) in [(1.0, 2.0), (2.0, 1.0), (-1.0, -2.0), (-2.0, -1.0)] {
+            assert!(contains(
+                &solutions,
+                [Complex64::new(x, 0.0), Complex64::new(y, 0.0)]
+            ));
+        }
+    }
+
+    #[test]
+    fn test_complex_solutions() {
+        let solutions = solve(&["x^2+1", "y+-2x+-1"]);
+
+        assert_eq!(solutions.len(), 2);
+        assert!(solutions.iter().all(|solution| solution.residual() < 1e-8));
+        assert!(contains(
+            &solutions,
+            [Complex64::new(0.0, 1.0), Complex64::new(1.0, 2.0)]
+        ));
+        assert!(contains(
+            &solutions,
+            [Complex64::new(0.0, -1.0), Complex64::new(1.0, -2.0)]
+        ));
+    }
+}
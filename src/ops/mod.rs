@@ -1,3 +1,6 @@
+pub mod module;
 pub mod monomial;
 pub mod polynomial;
+pub mod quotient;
 pub mod term;
+pub mod univariate;
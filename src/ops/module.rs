@@ -0,0 +1,123 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::{
+    module::{add_mod_mod, mul_poly_mod, sub_mod_mod, ModuleElement},
+    order::Order,
+    polynomial::Polynomial,
+    ring::Ring,
+    variable::Variable,
+};
+
+// ops mod + mod
+
+impl<R: Ring, V: Variable, O: Order> Add<ModuleElement<R, V, O>> for ModuleElement<R, V, O> {
+    type Output = ModuleElement<R, V, O>;
+
+    #[inline]
+    fn add(self, rhs: ModuleElement<R, V, O>) -> Self::Output {
+        add_mod_mod(&self, &rhs)
+    }
+}
+
+impl<R: Ring, V: Variable, O: Order> Add<&ModuleElement<R, V, O>> for ModuleElement<R, V, O> {
+    type Output = ModuleElement<R, V, O>;
+
+    #[inline]
+    fn add(self, rhs: &ModuleElement<R, V, O>) -> Self::Output {
+        add_mod_mod(&self, rhs)
+    }
+}
+
+impl<R: Ring, V: Variable, O: Order> Add<ModuleElement<R, V, O>> for &ModuleElement<R, V, O> {
+    type Output = ModuleElement<R, V, O>;
+
+    #[inline]
+    fn add(self, rhs: ModuleElement<R, V, O>) -> Self::Output {
+        add_mod_mod(self, &rhs)
+    }
+}
+
+impl<R: Ring, V: Variable, O: Order> Add<&ModuleElement<R, V, O>> for &ModuleElement<R, V, O> {
+    type Output = ModuleElement<R, V, O>;
+
+    #[inline]
+    fn add(self, rhs: &ModuleElement<R, V, O>) -> Self::Output {
+        add_mod_mod(self, rhs)
+    }
+}
+
+// ops mod - mod
+
+impl<R: Ring, V: Variable, O: Order> Sub<ModuleElement<R, V, O>> for ModuleElement<R, V, O> {
+    type Output = ModuleElement<R, V, O>;
+
+    #[inline]
+    fn sub(self, rhs: ModuleElement<R, V, O>) -> Self::Output {
+        sub_mod_mod(&self, &rhs)
+    }
+}
+
+impl<R: Ring, V: Variable, O: Order> Sub<&ModuleElement<R, V, O>> for ModuleElement<R, V, O> {
+    type Output = ModuleElement<R, V, O>;
+
+    #[inline]
+    fn sub(self, rhs: &ModuleElement<R, V, O>) -> Self::Output {
+        sub_mod_mod(&self, rhs)
+    }
+}
+
+impl<R: Ring, V: Variable, O: Order> Sub<ModuleElement<R, V, O>> for &ModuleElement<R, V, O> {
+    type Output = ModuleElement<R, V, O>;
+
+    #[inline]
+    fn sub(self, rhs: ModuleElement<R, V, O>) -> Self::Output {
+        sub_mod_mod(self, &rhs)
+    }
+}
+
+impl<R: Ring, V: Variable, O: Order> Sub<&ModuleElement<R, V, O>> for &ModuleElement<R, V, O> {
+    type Output = ModuleElement<R, V, O>;
+
+    #[inline]
+    fn sub(self, rhs: &ModuleElement<R, V, O>) -> Self::Output {
+        sub_mod_mod(self, rhs)
+    }
+}
+
+// ops poly * mod
+
+impl<R: Ring, V: Variable, O: Order> Mul<ModuleElement<R, V, O>> for Polynomial<R, V, O> {
+    type Output = ModuleElement<R, V, O>;
+
+    #[inline]
+    fn mul(self, rhs: ModuleElement<R, V, O>) -> Self::Output {
+        mul_poly_mod(&self, &rhs)
+    }
+}
+
+impl<R: Ring, V: Variable, O: Order> Mul<&ModuleElement<R, V, O>> for Polynomial<R, V, O> {
+    type Output = ModuleElement<R, V, O>;
+
+    #[inline]
+    fn mul(self, rhs: &ModuleElement<R, V, O>) -> Self::Output {
+        mul_poly_mod(&self, rhs)
+    }
+}
+
+impl<R: Ring, V: Variable, O: Order> Mul<ModuleElement<R, V, O>> for &Polynomial<R, V, O> {
+    type Output = ModuleElement<R, V, O>;
+
+    #[inline]
+    fn mul(self, rhs: ModuleElement<R, V, O>) -> Self::Output {
+        mul_poly_mod(self, &rhs)
+    }
+}
+
+impl<R: Ring, V: Variable, O: Order> Mul<&ModuleElement<R, V, O>> for &Polynomial<R, V, O> {
+    type Output = ModuleElement<R, V, O>;
+
+    #[inline]
+    fn mul(self, rhs: &ModuleElement<R, V, O>) -> Self::Output {
+        mul_poly_mod(self, rhs)
+    }
+}
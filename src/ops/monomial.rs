@@ -1,8 +1,10 @@
 use std::ops::Mul;
 
+use num::traits::Pow;
+
 use crate::{
     impl_ring_mono_mul, impl_ring_term_mul,
-    monomial::{mul_mono_mono, mul_ring_mono, mul_ring_term, mul_term_mono, Monomial},
+    monomial::{mul_mono_mono, mul_ring_mono, mul_ring_term, mul_term_mono, pow_mono, Monomial},
     ring::Ring,
     term::Term,
     variable::Variable,
@@ -165,3 +167,23 @@ impl<R: Ring, V: Variable> Mul<&Monomial<R, V>> for &Monomial<R, V> {
         mul_mono_mono(self, rhs)
     }
 }
+
+// ops mono ^ u32
+
+impl<R: Ring, V: Variable> Pow<u32> for Monomial<R, V> {
+    type Output = Monomial<R, V>;
+
+    #[inline]
+    fn pow(self, rhs: u32) -> Self::Output {
+        pow_mono(&self, rhs)
+    }
+}
+
+impl<R: Ring, V: Variable> Pow<u32> for &Monomial<R, V> {
+    type Output = Monomial<R, V>;
+
+    #[inline]
+    fn pow(self, rhs: u32) -> Self::Output {
+        pow_mono(self, rhs)
+    }
+}
@@ -1,10 +1,13 @@
 use std::ops::{Add, Div, Mul, Rem, Sub};
 
+use num::traits::Pow;
+
 use crate::{
     monomial::Monomial,
     order::Order,
     polynomial::{
-        add_poly_poly, div_poly_poly, mul_any_poly, mul_poly_poly, sub_poly_poly, Polynomial,
+        add_poly_poly, div_poly_poly, mul_any_poly, mul_poly_poly, pow_poly, sub_poly_poly,
+        Polynomial,
     },
     ring::Ring,
     term::Term,
@@ -320,3 +323,23 @@ where
         div_poly_poly(self, rhs)
     }
 }
+
+// ops poly ^ u32
+
+impl<R: Ring, V: Variable, O: Order> Pow<u32> for Polynomial<R, V, O> {
+    type Output = Polynomial<R, V, O>;
+
+    #[inline]
+    fn pow(self, rhs: u32) -> Self::Output {
+        pow_poly(&self, rhs)
+    }
+}
+
+impl<R: Ring, V: Variable, O: Order> Pow<u32> for &Polynomial<R, V, O> {
+    type Output = Polynomial<R, V, O>;
+
+    #[inline]
+    fn pow(self, rhs: u32) -> Self::Output {
+        pow_poly(self, rhs)
+    }
+}
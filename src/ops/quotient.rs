@@ -0,0 +1,89 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use num::traits::Pow;
+
+use crate::{
+    monomial::{mul_ring_mono, mul_ring_term, Monomial},
+    quotient::{
+        add_quo_quo, mul_quo_quo, neg_quo, pow_quo, sub_quo_quo, QuotientElement, StaticIdeal,
+    },
+    term::Term,
+    variable::Variable,
+};
+
+// ops quo + quo
+
+impl<I: StaticIdeal<N>, const N: usize> Add<QuotientElement<I, N>> for QuotientElement<I, N> {
+    type Output = QuotientElement<I, N>;
+
+    #[inline]
+    fn add(self, rhs: QuotientElement<I, N>) -> Self::Output {
+        add_quo_quo(self, rhs)
+    }
+}
+
+// ops quo - quo
+
+impl<I: StaticIdeal<N>, const N: usize> Sub<QuotientElement<I, N>> for QuotientElement<I, N> {
+    type Output = QuotientElement<I, N>;
+
+    #[inline]
+    fn sub(self, rhs: QuotientElement<I, N>) -> Self::Output {
+        sub_quo_quo(self, rhs)
+    }
+}
+
+// ops quo * quo
+
+impl<I: StaticIdeal<N>, const N: usize> Mul<QuotientElement<I, N>> for QuotientElement<I, N> {
+    type Output = QuotientElement<I, N>;
+
+    #[inline]
+    fn mul(self, rhs: QuotientElement<I, N>) -> Self::Output {
+        mul_quo_quo(self, rhs)
+    }
+}
+
+// ops quo ^ u32
+
+impl<I: StaticIdeal<N>, const N: usize> Pow<u32> for QuotientElement<I, N> {
+    type Output = QuotientElement<I, N>;
+
+    #[inline]
+    fn pow(self, rhs: u32) -> Self::Output {
+        pow_quo(self, rhs)
+    }
+}
+
+// ops -quo
+
+impl<I: StaticIdeal<N>, const N: usize> Neg for QuotientElement<I, N> {
+    type Output = QuotientElement<I, N>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        neg_quo(self)
+    }
+}
+
+// ops quo * term and quo * mono, as for the other coefficient rings
+
+impl<I: StaticIdeal<N>, const N: usize, V: Variable> Mul<Term<V>> for QuotientElement<I, N> {
+    type Output = Monomial<QuotientElement<I, N>, V>;
+
+    #[inline]
+    fn mul(self, rhs: Term<V>) -> Self::Output {
+        mul_ring_term(self, rhs)
+    }
+}
+
+impl<I: StaticIdeal<N>, const N: usize, V: Variable> Mul<Monomial<QuotientElement<I, N>, V>>
+    for QuotientElement<I, N>
+{
+    type Output = Monomial<QuotientElement<I, N>, V>;
+
+    #[inline]
+    fn mul(self, rhs: Monomial<QuotientElement<I, N>, V>) -> Self::Output {
+        mul_ring_mono(self, rhs)
+    }
+}
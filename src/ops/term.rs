@@ -1,7 +1,9 @@
 use std::ops::{Div, Mul};
 
+use num::traits::Pow;
+
 use crate::{
-    term::{div_term_term, mul_term_term, Term},
+    term::{div_term_term, mul_term_term, pow_term, Term},
     variable::Variable,
 };
 
@@ -80,3 +82,23 @@ impl<V: Variable> Div<&Term<V>> for &Term<V> {
         div_term_term(self, rhs)
     }
 }
+
+// ops term ^ u32
+
+impl<V: Variable> Pow<u32> for Term<V> {
+    type Output = Term<V>;
+
+    #[inline]
+    fn pow(self, rhs: u32) -> Self::Output {
+        pow_term(&self, rhs)
+    }
+}
+
+impl<V: Variable> Pow<u32> for &Term<V> {
+    type Output = Term<V>;
+
+    #[inline]
+    fn pow(self, rhs: u32) -> Self::Output {
+        pow_term(self, rhs)
+    }
+}
@@ -0,0 +1,140 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{
+    ring::Ring,
+    univariate::{add_uni_uni, mul_uni_uni, neg_uni, sub_uni_uni, UnivariatePolynomial},
+};
+
+// ops uni + uni
+
+impl<R: Ring> Add<UnivariatePolynomial<R>> for UnivariatePolynomial<R> {
+    type Output = UnivariatePolynomial<R>;
+
+    #[inline]
+    fn add(self, rhs: UnivariatePolynomial<R>) -> Self::Output {
+        add_uni_uni(&self, &rhs)
+    }
+}
+
+impl<R: Ring> Add<&UnivariatePolynomial<R>> for UnivariatePolynomial<R> {
+    type Output = UnivariatePolynomial<R>;
+
+    #[inline]
+    fn add(self, rhs: &UnivariatePolynomial<R>) -> Self::Output {
+        add_uni_uni(&self, rhs)
+    }
+}
+
+impl<R: Ring> Add<UnivariatePolynomial<R>> for &UnivariatePolynomial<R> {
+    type Output = UnivariatePolynomial<R>;
+
+    #[inline]
+    fn add(self, rhs: UnivariatePolynomial<R>) -> Self::Output {
+        add_uni_uni(self, &rhs)
+    }
+}
+
+impl<R: Ring> Add<&UnivariatePolynomial<R>> for &UnivariatePolynomial<R> {
+    type Output = UnivariatePolynomial<R>;
+
+    #[inline]
+    fn add(self, rhs: &UnivariatePolynomial<R>) -> Self::Output {
+        add_uni_uni(self, rhs)
+    }
+}
+
+// ops uni - uni
+
+impl<R: Ring> Sub<UnivariatePolynomial<R>> for UnivariatePolynomial<R> {
+    type Output = UnivariatePolynomial<R>;
+
+    #[inline]
+    fn sub(self, rhs: UnivariatePolynomial<R>) -> Self::Output {
+        sub_uni_uni(&self, &rhs)
+    }
+}
+
+impl<R: Ring> Sub<&UnivariatePolynomial<R>> for UnivariatePolynomial<R> {
+    type Output = UnivariatePolynomial<R>;
+
+    #[inline]
+    fn sub(self, rhs: &UnivariatePolynomial<R>) -> Self::Output {
+        sub_uni_uni(&self, rhs)
+    }
+}
+
+impl<R: Ring> Sub<UnivariatePolynomial<R>> for &UnivariatePolynomial<R> {
+    type Output = UnivariatePolynomial<R>;
+
+    #[inline]
+    fn sub(self, rhs: UnivariatePolynomial<R>) -> Self::Output {
+        sub_uni_uni(self, &rhs)
+    }
+}
+
+impl<R: Ring> Sub<&UnivariatePolynomial<R>> for &UnivariatePolynomial<R> {
+    type Output = UnivariatePolynomial<R>;
+
+    #[inline]
+    fn sub(self, rhs: &UnivariatePolynomial<R>) -> Self::Output {
+        sub_uni_uni(self, rhs)
+    }
+}
+
+// ops uni * uni
+
+impl<R: Ring> Mul<UnivariatePolynomial<R>> for UnivariatePolynomial<R> {
+    type Output = UnivariatePolynomial<R>;
+
+    #[inline]
+    fn mul(self, rhs: UnivariatePolynomial<R>) -> Self::Output {
+        mul_uni_uni(&self, &rhs)
+    }
+}
+
+impl<R: Ring> Mul<&UnivariatePolynomial<R>> for UnivariatePolynomial<R> {
+    type Output = UnivariatePolynomial<R>;
+
+    #[inline]
+    fn mul(self, rhs: &UnivariatePolynomial<R>) -> Self::Output {
+        mul_uni_uni(&self, rhs)
+    }
+}
+
+impl<R: Ring> Mul<UnivariatePolynomial<R>> for &UnivariatePolynomial<R> {
+    type Output = UnivariatePolynomial<R>;
+
+    #[inline]
+    fn mul(self, rhs: UnivariatePolynomial<R>) -> Self::Output {
+        mul_uni_uni(self, &rhs)
+    }
+}
+
+impl<R: Ring> Mul<&UnivariatePolynomial<R>> for &UnivariatePolynomial<R> {
+    type Output = UnivariatePolynomial<R>;
+
+    #[inline]
+    fn mul(self, rhs: &UnivariatePolynomial<R>) -> Self::Output {
+        mul_uni_uni(self, rhs)
+    }
+}
+
+// ops -uni
+
+impl<R: Ring> Neg for UnivariatePolynomial<R> {
+    type Output = UnivariatePolynomial<R>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        neg_uni(&self)
+    }
+}
+
+impl<R: Ring> Neg for &UnivariatePolynomial<R> {
+    type Output = UnivariatePolynomial<R>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        neg_uni(self)
+    }
+}
@@ -60,6 +60,90 @@ impl Order for GradLex {
     }
 }
 
+/// Order on the terms `t * e_i` of a free module, given by the position `i`
+/// and the term order `O` on `t`. Smaller positions are greater, as smaller
+/// variables are for [`Lex`].
+pub trait ModuleOrder {
+    fn cmp<V: Variable, O: Order>(left: (usize, &Term<V>), right: (usize, &Term<V>)) -> Ordering;
+}
+
+/// Position over term, compare positions first and terms on ties.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PositionOverTerm {}
+
+impl ModuleOrder for PositionOverTerm {
+    fn cmp<V: Variable, O: Order>(left: (usize, &Term<V>), right: (usize, &Term<V>)) -> Ordering {
+        right.0.cmp(&left.0).then_with(|| O::cmp(left.1, right.1))
+    }
+}
+
+/// Term over position, compare terms first and positions on ties.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TermOverPosition {}
+
+impl ModuleOrder for TermOverPosition {
+    fn cmp<V: Variable, O: Order>(left: (usize, &Term<V>), right: (usize, &Term<V>)) -> Ordering {
+        O::cmp(left.1, right.1).then_with(|| right.0.cmp(&left.0))
+    }
+}
+
+/// Order chosen at runtime, comparing terms by their weight first and
+/// breaking ties with the order `O`.
+#[derive(Debug)]
+pub struct WeightOrder<V: Variable, O: Order> {
+    weights: Vec<(V, i64)>,
+    tie_break: PhantomData<O>,
+}
+
+impl<V: Variable, O: Order> WeightOrder<V, O> {
+    pub fn new(variables: &[V], weights: &[i64]) -> Self {
+        assert_eq!(variables.len(), weights.len(), "One weight per variable");
+        let mut weights: Vec<(V, i64)> = variables
+            .iter()
+            .cloned()
+            .zip(weights.iter().cloned())
+            .collect();
+        weights.sort();
+        Self {
+            weights,
+            tie_break: PhantomData,
+        }
+    }
+
+    /// Weights in increasing order of the variables.
+    pub fn weights(&self) -> Vec<i64> {
+        self.weights.iter().map(|&(_, w)| w).collect()
+    }
+
+    pub fn weight(&self, term: &Term<V>) -> i64 {
+        term.exps
+            .iter()
+            .map(
+                |&(var, exp)| match self.weights.binary_search_by(|(v, _)| v.cmp(&var)) {
+                    Ok(i) => exp as i64 * self.weights[i].1,
+                    Err(_) => 0,
+                },
+            )
+            .sum()
+    }
+
+    pub fn cmp(&self, left: &Term<V>, right: &Term<V>) -> Ordering {
+        match self.weight(left).cmp(&self.weight(right)) {
+            Ordering::Equal => O::cmp(left, right),
+            c => c,
+        }
+    }
+}
+
+impl<V: Variable, O: Order> Clone for WeightOrder<V, O> {
+    fn clone(&self) -> Self {
+        Self {
+            weights: self.weights.clone(),
+            tie_break: PhantomData,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct OrderedTerm<V: Variable, O: Order> {
     terms: Term<V>,
@@ -137,4 +221,45 @@ mod tests {
 
         assert_eq!(terms, vec![b, b2, a, ab, a2]);
     }
+
+    #[test]
+    fn test_grad_lex_order() {
+        take_order::<GradLex>();
+
+        let one = Term::default();
+        let a2 = Term::from_str("a^2").unwrap();
+        let ab = Term::from_str("ab").unwrap();
+        let a = Term::from_str("a").unwrap();
+        let b3 = Term::from_str("b^3").unwrap();
+        let b = Term::from_str("b").unwrap();
+
+        let mut terms: Vec<Term<Var>> = vec![
+            a.clone(),
+            b3.clone(),
+            a2.clone(),
+            one.clone(),
+            b.clone(),
+            ab.clone(),
+        ];
+        terms.sort_by(GradLex::cmp);
+
+        assert_eq!(terms, vec![one, b, a, ab, a2, b3]);
+    }
+
+    #[test]
+    fn test_weight_order() {
+        let a2 = Term::from_str("a^2").unwrap();
+        let ab = Term::from_str("ab").unwrap();
+        let b3 = Term::from_str("b^3").unwrap();
+        let c = Term::from_str("c").unwrap();
+
+        let order: WeightOrder<Var, Lex> = WeightOrder::new(&[Var(2), Var(0), Var(1)], &[5, 1, 2]);
+        assert_eq!(order.weights(), vec![1, 2, 5]);
+        assert_eq!(order.weight(&ab), 3);
+
+        let mut terms: Vec<Term<Var>> = vec![a2.clone(), c.clone(), b3.clone(), ab.clone()];
+        terms.sort_by(|left, right| order.cmp(left, right));
+
+        assert_eq!(terms, vec![a2, ab, c, b3]);
+    }
 }
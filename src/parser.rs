@@ -51,7 +51,7 @@ where
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let re_parse_mono =
-            Regex::new(r"^\s*(?<coeff>(?:\-?\d+)|\-)?\s*(?<term>[\S\s]*)?").unwrap();
+            Regex::new(r"^\s*(?<coeff>(?:\-?\d+(?:/\d+)?)|\-)?\s*(?<term>[\S\s]*)?").unwrap();
 
         if let Some(captured) = re_parse_mono.captures(s) {
             let term: Term<V> = captured
@@ -66,10 +66,7 @@ where
                 })
                 .or(Err("Cannot parse a coefficient"))?;
 
-            Ok(Monomial {
-                coeff: coeff,
-                term: term,
-            })
+            Ok(Monomial { coeff, term })
         } else {
             Err("Invalid monomial".to_owned())
         }
@@ -92,9 +89,7 @@ where
             monomials.insert(monomial.term.into(), monomial.coeff);
         }
 
-        Ok(Polynomial {
-            monomials: monomials,
-        })
+        Ok(Polynomial { monomials })
     }
 }
 
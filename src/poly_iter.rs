@@ -34,9 +34,7 @@ impl<B: Borrow<Monomial<R, V>>, R: Ring, V: Variable, O: Order> FromIterator<B>
             }
         }
 
-        Polynomial {
-            monomials: monomials,
-        }
+        Polynomial { monomials }
     }
 }
 
@@ -64,7 +62,7 @@ impl<I: Iterator<Item = (OrderedTerm<V, O>, R)>, R: Ring, V: Variable, O: Order>
 
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().map(|(term, coeff)| Monomial {
-            coeff: coeff,
+            coeff,
             term: (*term).clone(),
         })
     }
@@ -92,14 +90,14 @@ impl<
 
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().map(|(term, coeff)| Monomial {
-            coeff: coeff.clone(),
+            coeff: *coeff,
             term: term.deref().clone(),
         })
     }
 }
 
 impl<R: Ring, V: Variable, O: Order> Polynomial<R, V, O> {
-    pub fn iter(&self) -> MonomialRefIter<Iter<'_, OrderedTerm<V, O>, R>, R, V, O> {
+    pub fn iter(&self) -> MonomialRefIter<'_, Iter<'_, OrderedTerm<V, O>, R>, R, V, O> {
         MonomialRefIter {
             iter: self.monomials.iter(),
         }
@@ -1,18 +1,19 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 use std::ops::{Div, Mul, Rem};
 
+use itertools::Itertools;
 use num::{One, Zero};
 
-use crate::monomial::Monomial;
+use crate::monomial::{pow_mono, Monomial};
 use crate::order::{Lex, Order, OrderedTerm};
 use crate::term::lcm;
 use crate::variable::{Var, Variable};
 
 use super::term::{Degree, Term};
 
-use super::ring::Ring;
+use super::ring::{Field, Ring};
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug)]
 pub struct Polynomial<R: Ring = i32, V: Variable = Var, O: Order = Lex> {
     pub(crate) monomials: BTreeMap<OrderedTerm<V, O>, R>,
 }
@@ -50,11 +51,19 @@ impl<R: Ring, V: Variable, O: Order> Clone for Polynomial<R, V, O> {
     }
 }
 
+impl<R: Ring, V: Variable, O: Order> PartialEq for Polynomial<R, V, O> {
+    fn eq(&self, other: &Self) -> bool {
+        self.monomials == other.monomials
+    }
+}
+
+impl<R: Ring, V: Variable, O: Order> Eq for Polynomial<R, V, O> {}
+
 impl<R: Ring, V: Variable, O: Order> Degree for Polynomial<R, V, O> {
     fn deg(&self) -> usize {
         self.monomials
-            .iter()
-            .map(|(term, _)| term.deg())
+            .keys()
+            .map(|term| term.deg())
             .max()
             .unwrap_or(0)
     }
@@ -121,6 +130,60 @@ pub(crate) fn mul_poly_poly<R: Ring, V: Variable, O: Order>(
     monomials.into_iter().collect()
 }
 
+/// `f^exp` by repeated squaring, expanded directly for monomials and
+/// binomials with coefficients from Pascal's triangle.
+pub(crate) fn pow_poly<R: Ring, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    exp: u32,
+) -> Polynomial<R, V, O> {
+    if exp == 0 {
+        return Polynomial::one();
+    }
+
+    let monomials: Vec<Monomial<R, V>> = f.iter().collect();
+    match &monomials[..] {
+        [] => Polynomial::zero(),
+        [m] => [pow_mono(m, exp)].into_iter().collect(),
+        [a, b] => {
+            // binomial coefficients as sums in the ring, valid in any characteristic
+            let mut binomials = vec![R::one()];
+            for _ in 0..exp {
+                let mut next = vec![R::one()];
+                next.extend(binomials.windows(2).map(|pair| pair[0] + pair[1]));
+                next.push(R::one());
+                binomials = next;
+            }
+            binomials
+                .into_iter()
+                .enumerate()
+                .map(|(k, c)| {
+                    let k = k as u32;
+                    &pow_mono(a, k) * &pow_mono(b, exp - k) * c
+                })
+                .collect()
+        }
+        _ => {
+            let mut result: Option<Polynomial<R, V, O>> = None;
+            let mut base = f.clone();
+            let mut exp = exp;
+            loop {
+                if exp % 2 == 1 {
+                    result = Some(match result {
+                        Some(r) => mul_poly_poly(&r, &base),
+                        None => base.clone(),
+                    });
+                }
+                exp /= 2;
+                if exp == 0 {
+                    break;
+                }
+                base = mul_poly_poly(&base, &base);
+            }
+            result.unwrap()
+        }
+    }
+}
+
 #[inline]
 pub(crate) fn add_poly_poly<R: Ring, V: Variable, O: Order>(
     left: &Polynomial<R, V, O>,
@@ -143,18 +206,39 @@ pub(crate) fn sub_poly_poly<R: Ring, V: Variable, O: Order>(
 }
 
 #[inline]
-pub(crate) fn div_poly_poly<R: Ring, V: Variable, O: Order>(
+pub(crate) fn sub_mono_poly_assign<R: Ring, V: Variable, O: Order>(
+    left: &mut Polynomial<R, V, O>,
+    mono: &Monomial<R, V>,
+    right: &Polynomial<R, V, O>,
+) {
+    for m in right.iter() {
+        let product = m * mono;
+        let term: OrderedTerm<V, O> = product.term.into();
+        if let Some(coeff) = left.monomials.get_mut(&term) {
+            *coeff = *coeff - product.coeff;
+            if coeff.is_zero() {
+                left.monomials.remove(&term);
+            }
+        } else {
+            left.monomials.insert(term, -product.coeff);
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn div_poly_poly<R, V: Variable, O: Order>(
     left: &Polynomial<R, V, O>,
     right: &Polynomial<R, V, O>,
 ) -> (Polynomial<R, V, O>, Polynomial<R, V, O>)
 where
-    R: Rem<R, Output = R> + Div<R, Output = R>,
+    R: Ring + Rem<R, Output = R> + Div<R, Output = R>,
 {
     let mut f: Polynomial<R, V, O> = left.clone();
 
     let mut rem_monomial: Vec<Monomial<R, V>> = Default::default();
 
-    loop {
+    // the lead term of zero is 1, which any constant would divide forever
+    while !f.is_zero() {
         if (f.lead_coeff() % right.lead_coeff()) == R::zero() {
             let c = f.lead_coeff() / right.lead_coeff();
             if let Some(m) = f.lead_term() / right.lead_term() {
@@ -182,54 +266,69 @@ where
         - ((&m / &g.lead_term()).unwrap() * f.lead_coeff()) * g
 }
 
-pub fn buchberger<R: Ring, V: Variable, O: Order>(
-    polys: &Vec<Polynomial<R, V, O>>,
-) -> Vec<Polynomial<R, V, O>>
+/// Divide `f` by every element of `basis` until its lead term is divisible
+/// by none of their lead terms. A single pass may expose a lead term
+/// divisible by an earlier element.
+fn reduce_lead<R, V: Variable, O: Order>(
+    mut f: Polynomial<R, V, O>,
+    basis: &[Polynomial<R, V, O>],
+) -> Polynomial<R, V, O>
 where
-    R: Rem<R, Output = R> + Div<R, Output = R>,
+    R: Ring + Rem<R, Output = R> + Div<R, Output = R>,
 {
-    let mut g = polys.clone();
-
     loop {
-        let mut next_ideal: Option<Polynomial<R, V, O>> = None;
-        for (i, g_i) in g.iter().enumerate() {
-            for (j, g_j) in g.iter().enumerate() {
-                if i == j {
-                    continue;
-                }
-                let mut s_ij = sploy(g_i, g_j);
-                for g_k in g.iter() {
-                    (_, s_ij) = s_ij / g_k;
-                }
-                if !s_ij.monomials.is_empty() {
-                    next_ideal = Some(s_ij);
-                }
-
-                if next_ideal.is_some() {
-                    break;
-                }
-            }
-
-            if next_ideal.is_some() {
-                break;
-            }
+        let lead = f.lead_term();
+        for g in basis {
+            (_, f) = f / g;
         }
-        if let Some(ideal) = next_ideal {
-            g.push(ideal);
-        } else {
-            break;
+        if f.is_zero() || f.lead_term() == lead {
+            return f;
+        }
+    }
+}
+
+/// Buchberger's loop, shared by the polynomial and module variants: every
+/// pair `(i, j)` of elements of `basis`, oldest first, is reduced by
+/// `reduce_pair`, and each nonzero result is appended to `basis` and paired
+/// with the earlier elements.
+pub(crate) fn buchberger_loop<T>(
+    basis: &mut Vec<T>,
+    mut reduce_pair: impl FnMut(&[T], usize, usize) -> Option<T>,
+) {
+    let mut pairs: VecDeque<(usize, usize)> = (0..basis.len())
+        .flat_map(|j| (0..j).map(move |i| (i, j)))
+        .collect();
+    while let Some((i, j)) = pairs.pop_front() {
+        if let Some(remainder) = reduce_pair(basis, i, j) {
+            let k = basis.len();
+            basis.push(remainder);
+            pairs.extend((0..k).map(|i| (i, k)));
         }
     }
+}
+
+/// Gröbner basis of the ideal of `polys`, interreduced. The same loop with
+/// the option of tracking how each element arises from `polys` is
+/// [`tracked_buchberger`](crate::lift::tracked_buchberger).
+pub fn buchberger<R, V: Variable, O: Order>(
+    polys: &[Polynomial<R, V, O>],
+) -> Vec<Polynomial<R, V, O>>
+where
+    R: Ring + Rem<R, Output = R> + Div<R, Output = R>,
+{
+    let mut g = polys.to_vec();
+    buchberger_loop(&mut g, |g, i, j| {
+        let s = reduce_lead(sploy(&g[i], &g[j]), g);
+        (!s.is_zero()).then_some(s)
+    });
 
     g.sort_by(|left, right| O::cmp(&left.lead_term(), &right.lead_term()).reverse());
     reduce(g)
 }
 
-fn reduce<R: Ring, V: Variable, O: Order>(
-    polys: Vec<Polynomial<R, V, O>>,
-) -> Vec<Polynomial<R, V, O>>
+fn reduce<R, V: Variable, O: Order>(polys: Vec<Polynomial<R, V, O>>) -> Vec<Polynomial<R, V, O>>
 where
-    R: Rem<R, Output = R> + Div<R, Output = R>,
+    R: Ring + Rem<R, Output = R> + Div<R, Output = R>,
     Term<V>: Mul<R, Output = Monomial<R, V>>,
 {
     let mut reduced: Vec<Polynomial<R, V, O>> = Default::default();
@@ -253,11 +352,185 @@ where
     reduced
 }
 
+/// Reduced Gröbner basis of the elimination ideal `I ∩ k[y]`, where `y` are
+/// the variables other than `variables`.
+///
+/// A [`Lex`] basis contains a basis of the elimination ideal as long as the
+/// eliminated variables are greater than the kept ones, which is asserted.
+pub fn eliminate<R, V: Variable>(
+    generators: &[Polynomial<R, V, Lex>],
+    variables: &[V],
+) -> Vec<Polynomial<R, V, Lex>>
+where
+    R: Field + Rem<R, Output = R>,
+{
+    let greater =
+        |v: V, kept: V| Lex::cmp(&Term::from_iter([(v, 1)]), &Term::from_iter([(kept, 1)])).is_gt();
+    assert!(
+        generators
+            .iter()
+            .flat_map(|f| f.iter())
+            .flat_map(|m| m.term.exps)
+            .filter(|(kept, _)| !variables.contains(kept))
+            .all(|(kept, _)| variables.iter().all(|&v| greater(v, kept))),
+        "The eliminated variables must be greater than the others for Lex"
+    );
+
+    reduced_basis(&buchberger(generators))
+        .into_iter()
+        .filter(|g| {
+            !g.iter()
+                .any(|m| m.term.exps.iter().any(|(v, _)| variables.contains(v)))
+        })
+        .collect()
+}
+
+pub fn is_homogeneous<R: Ring, V: Variable, O: Order>(f: &Polynomial<R, V, O>) -> bool {
+    f.monomials.keys().map(|term| term.deg()).all_equal()
+}
+
+pub fn monic<R: Field, V: Variable, O: Order>(f: &Polynomial<R, V, O>) -> Polynomial<R, V, O> {
+    if f.is_zero() {
+        return f.clone();
+    }
+
+    let inv = f.lead_coeff().inv();
+    Polynomial {
+        monomials: f
+            .monomials
+            .iter()
+            .map(|(term, &coeff)| (term.clone(), coeff * inv))
+            .collect(),
+    }
+}
+
+/// Fully reduce `f` by `basis`, every monomial of the result is irreducible.
+///
+/// When `basis` is a Gröbner basis the result is the unique normal form of `f`
+/// modulo the ideal.
+pub fn normal_form<R: Field, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    basis: &[Polynomial<R, V, O>],
+) -> Polynomial<R, V, O> {
+    normal_form_quotients(f, basis).1
+}
+
+/// Quotients by each element of a basis and the remainder.
+pub(crate) type Division<R, V, O> = (Vec<Polynomial<R, V, O>>, Polynomial<R, V, O>);
+
+/// Lead term and coefficient of a polynomial for some term order, either its
+/// order `O` or one chosen at runtime.
+pub(crate) type Lead<'a, R, V, O> = dyn Fn(&Polynomial<R, V, O>) -> Option<(Term<V>, R)> + 'a;
+
+fn lead_monomial<R: Ring, V: Variable, O: Order>(f: &Polynomial<R, V, O>) -> Option<(Term<V>, R)> {
+    f.monomials
+        .last_key_value()
+        .map(|(term, &coeff)| ((**term).clone(), coeff))
+}
+
+/// [`normal_form`] together with the quotients, so that
+/// `f = sum(quotients[i] * basis[i]) + remainder`.
+pub(crate) fn normal_form_quotients<R: Field, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    basis: &[Polynomial<R, V, O>],
+) -> Division<R, V, O> {
+    normal_form_quotients_by(f, basis, &lead_monomial)
+}
+
+/// [`normal_form_quotients`] with the lead terms given by `lead`.
+pub(crate) fn normal_form_quotients_by<R: Field, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    basis: &[Polynomial<R, V, O>],
+    lead: &Lead<R, V, O>,
+) -> Division<R, V, O> {
+    let leads: Vec<Option<(Term<V>, R)>> = basis.iter().map(lead).collect();
+    let mut f = f.clone();
+    let mut quotients: Vec<Vec<Monomial<R, V>>> = vec![Default::default(); basis.len()];
+    let mut remainder: BTreeMap<OrderedTerm<V, O>, R> = Default::default();
+
+    while let Some((term, coeff)) = lead(&f) {
+        let divisor = leads.iter().enumerate().find_map(|(k, g)| {
+            let (lead_term, lead_coeff) = g.as_ref()?;
+            (&term / lead_term).map(|m| (k, m * (coeff / *lead_coeff)))
+        });
+
+        if let Some((k, mono)) = divisor {
+            sub_mono_poly_assign(&mut f, &mono, &basis[k]);
+            quotients[k].push(mono);
+        } else {
+            let term = term.into();
+            f.monomials.remove(&term);
+            remainder.insert(term, coeff);
+        }
+    }
+
+    (
+        quotients
+            .into_iter()
+            .map(|q| q.into_iter().collect())
+            .collect(),
+        Polynomial {
+            monomials: remainder,
+        },
+    )
+}
+
+/// Turn a Gröbner basis into the unique reduced Gröbner basis of its ideal:
+/// monic, with no monomial divisible by the lead term of another element.
+pub fn reduced_basis<R: Field, V: Variable, O: Order>(
+    basis: &[Polynomial<R, V, O>],
+) -> Vec<Polynomial<R, V, O>> {
+    let mut reduced = reduced_basis_by(basis, &lead_monomial);
+    reduced.sort_by(|left, right| O::cmp(&left.lead_term(), &right.lead_term()).reverse());
+    reduced
+}
+
+/// [`reduced_basis`] with the lead terms given by `lead`, unsorted.
+pub(crate) fn reduced_basis_by<R: Field, V: Variable, O: Order>(
+    basis: &[Polynomial<R, V, O>],
+    lead: &Lead<R, V, O>,
+) -> Vec<Polynomial<R, V, O>> {
+    let marked: Vec<_> = basis
+        .iter()
+        .filter_map(|g| lead(g).map(|(term, coeff)| (term, coeff, g)))
+        .collect();
+
+    let minimal: Vec<Polynomial<R, V, O>> = marked
+        .iter()
+        .enumerate()
+        .filter(|&(i, (lead, _, _))| {
+            !marked.iter().enumerate().any(|(j, (other, _, _))| {
+                i != j && (lead / other).is_some() && (lead != other || j < i)
+            })
+        })
+        .map(|(_, &(_, coeff, g))| g * (Term::default() * coeff.inv()))
+        .collect();
+
+    minimal
+        .iter()
+        .enumerate()
+        .map(|(i, g_i)| {
+            let others: Vec<Polynomial<R, V, O>> = minimal
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| i != j)
+                .map(|(_, g_j)| g_j.clone())
+                .collect();
+            normal_form_quotients_by(g_i, &others, lead).1
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use std::{cmp::Ordering, str::FromStr};
 
+    use num::traits::Pow;
+
     use super::*;
+    use crate::static_prime_field;
+
+    static_prime_field!(Z5(5:u32));
 
     #[test]
     fn test_add_polynomial() {
@@ -289,6 +562,26 @@ mod tests {
         assert_eq!(f * g, result);
     }
 
+    #[test]
+    fn test_pow_polynomial() {
+        let f: Polynomial<i32, Var> = Polynomial::from_str("x+-2yz+3").unwrap();
+        assert_eq!(
+            f.clone().pow(5),
+            (0..5).fold(Polynomial::one(), |acc, _| acc * &f)
+        );
+        assert!(f.pow(0).is_one());
+
+        let g: Polynomial<i32, Var> = Polynomial::from_str("2xy^2+-z").unwrap();
+        assert_eq!(
+            (&g).pow(3),
+            Polynomial::from_str("8x^3y^6+-12x^2y^4z+6xy^2z^2+-z^3").unwrap()
+        );
+
+        // the Frobenius map is additive in characteristic 5
+        let h: Polynomial<Z5, Var> = Polynomial::from_str("x+3y").unwrap();
+        assert_eq!(h.pow(5), Polynomial::from_str("x^5+3y^5").unwrap());
+    }
+
     #[test]
     fn test_div_polynomial() {
         let f: Polynomial<i32, Var, Lex> = Polynomial::from_str("x^2+-3xy+2x^2y^3+y^2+2").unwrap();
@@ -296,7 +589,7 @@ mod tests {
 
         let (q, r) = &f / &g;
 
-        assert!(Lex::cmp(&r.lead_term().into(), &g.lead_term().into()) == Ordering::Less);
+        assert!(Lex::cmp(&r.lead_term(), &g.lead_term()) == Ordering::Less);
         assert_eq!(f, q * g + r);
     }
 
@@ -318,4 +611,40 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_reduce_lead() {
+        // dividing x by x - y exposes y, divisible by the earlier y
+        let basis: Vec<Polynomial<i32, Var, Lex>> = vec![
+            Polynomial::from_str("y").unwrap(),
+            Polynomial::from_str("x+-y").unwrap(),
+        ];
+        let f = Polynomial::from_str("x").unwrap();
+        assert!(reduce_lead(f, &basis).is_zero());
+    }
+
+    #[test]
+    fn test_reduced_basis() {
+        let input: Vec<Polynomial<Z5, Var, Lex>> = vec![
+            Polynomial::from_str("x^2+-y").unwrap(),
+            Polynomial::from_str("x^3+-z").unwrap(),
+        ];
+
+        let grobner_basis = reduced_basis(&buchberger(&input));
+        assert_eq!(
+            grobner_basis,
+            vec![
+                Polynomial::from_str("x^2+4y").unwrap(),
+                Polynomial::from_str("xy+4z").unwrap(),
+                Polynomial::from_str("xz+4y^2").unwrap(),
+                Polynomial::from_str("y^3+4z^2").unwrap()
+            ]
+        );
+
+        let f: Polynomial<Z5, Var, Lex> = Polynomial::from_str("x^3+y^4+2").unwrap();
+        assert_eq!(
+            normal_form(&f, &grobner_basis),
+            Polynomial::from_str("yz^2+z+2").unwrap()
+        );
+    }
 }
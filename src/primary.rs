@@ -0,0 +1,201 @@
+use std::ops::Rem;
+
+use num::Zero;
+
+use crate::{
+    factor::Factorable,
+    order::Order,
+    polynomial::{buchberger, reduced_basis, Polynomial},
+    radical::{minimal_polynomial, radical_of_basis, PerfectField},
+    ring::{mul_by_integer, pow_ring},
+    term::Term,
+    univariate::UnivariatePolynomial,
+    variable::Variable,
+    zero_dim::quotient_dimension,
+};
+
+/// A primary component of an ideal and its associated prime, both given by
+/// their reduced Gröbner bases.
+#[derive(Debug)]
+pub struct PrimaryComponent<R: PerfectField, V: Variable, O: Order> {
+    primary: Vec<Polynomial<R, V, O>>,
+    prime: Vec<Polynomial<R, V, O>>,
+}
+
+impl<R: PerfectField, V: Variable, O: Order> Clone for PrimaryComponent<R, V, O> {
+    fn clone(&self) -> Self {
+        Self {
+            primary: self.primary.clone(),
+            prime: self.prime.clone(),
+        }
+    }
+}
+
+impl<R: PerfectField, V: Variable, O: Order> PartialEq for PrimaryComponent<R, V, O> {
+    fn eq(&self, other: &Self) -> bool {
+        self.primary == other.primary && self.prime == other.prime
+    }
+}
+
+impl<R: PerfectField, V: Variable, O: Order> Eq for PrimaryComponent<R, V, O> {}
+
+impl<R: PerfectField, V: Variable, O: Order> PrimaryComponent<R, V, O> {
+    pub fn primary(&self) -> &[Polynomial<R, V, O>] {
+        &self.primary
+    }
+
+    pub fn prime(&self) -> &[Polynomial<R, V, O>] {
+        &self.prime
+    }
+}
+
+/// `q(u)`, by Horner's rule.
+fn compose<R: PerfectField, V: Variable, O: Order>(
+    q: &UnivariatePolynomial<R>,
+    u: &Polynomial<R, V, O>,
+) -> Polynomial<R, V, O> {
+    q.coeffs().iter().rev().fold(Polynomial::zero(), |acc, &c| {
+        acc * u + Polynomial::from_iter([Term::default() * c])
+    })
+}
+
+/// Decomposition of the zero-dimensional ideal of `generators` into primary
+/// components, one for each of its associated primes, or none when the ideal
+/// is not zero-dimensional in `variables`, no separating linear form was
+/// found or its minimal polynomial could not be factored without overflow.
+/// The unit ideal has no components.
+///
+/// A linear form `u = x_1 + c x_2 + ... + c^(n-1) x_n` is searched for with
+/// `c = 0, 1, 2, ...` until it separates the solutions, that is until its
+/// minimal polynomial modulo the radical has the degree of the radical's
+/// quotient. Each irreducible factor `q^e` of the minimal polynomial of `u`
+/// modulo `I` then gives the primary component `I + (q(u)^e)` with the
+/// maximal ideal `sqrt(I) + (q(u))` as associated prime, following Gianni,
+/// Trager and Zacharias. Over small finite fields such a form may not exist.
+/// Over [`Rational`](crate::ring::Rational) it always exists, but the bases
+/// are computed with `i64` coefficients and panic when those overflow.
+pub fn primary_decomposition<R, V: Variable, O: Order>(
+    generators: &[Polynomial<R, V, O>],
+    variables: &[V],
+) -> Option<Vec<PrimaryComponent<R, V, O>>>
+where
+    R: PerfectField + Factorable + Rem<R, Output = R>,
+{
+    let basis = buchberger(generators);
+    let radical = radical_of_basis(&basis, variables)?;
+    let degree = quotient_dimension(&radical, variables)?;
+    if degree == 0 {
+        return Some(vec![]);
+    }
+
+    // a bad c is a root of the difference of u at two distinct solutions
+    let attempts = variables.len() * degree * degree + 1;
+    let u = (0..attempts).find_map(|c| {
+        let c = mul_by_integer(R::one(), c);
+        let u: Polynomial<R, V, O> = variables
+            .iter()
+            .enumerate()
+            .map(|(i, &var)| Term::from_iter([(var, 1)]) * pow_ring(c, i))
+            .collect();
+        (minimal_polynomial(&radical, &u).degree() == Some(degree)).then_some(u)
+    })?;
+
+    let components = R::factor_univariate(&minimal_polynomial(&basis, &u))
+        .ok()?
+        .into_iter()
+        .map(|(q, e)| {
+            let power = (1..e).fold(q.clone(), |acc, _| &acc * &q);
+            let primary: Vec<Polynomial<R, V, O>> =
+                basis.iter().cloned().chain([compose(&power, &u)]).collect();
+            let prime: Vec<Polynomial<R, V, O>> =
+                radical.iter().cloned().chain([compose(&q, &u)]).collect();
+            PrimaryComponent {
+                primary: reduced_basis(&buchberger(&primary)),
+                prime: reduced_basis(&buchberger(&prime)),
+            }
+        })
+        .collect();
+    Some(components)
+}
+
+/// The associated primes of a zero-dimensional ideal, all maximal and hence
+/// all minimal, as reduced Gröbner bases. See [`primary_decomposition`].
+pub fn associated_primes<R, V: Variable, O: Order>(
+    generators: &[Polynomial<R, V, O>],
+    variables: &[V],
+) -> Option<Vec<Vec<Polynomial<R, V, O>>>>
+where
+    R: PerfectField + Factorable + Rem<R, Output = R>,
+{
+    primary_decomposition(generators, variables)
+        .map(|components| components.into_iter().map(|c| c.prime).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use num::One;
+
+    use super::*;
+    use crate::{ring::Rational, static_prime_field, var, variable::Var};
+
+    static_prime_field!(Z7(7:u32));
+
+    #[test]
+    fn test_primary_decomposition_rational() {
+        // x^2 = y^2 = 2 splits into x = y and x = -y, but no eliminant factors
+        let generators: Vec<Polynomial<Rational, Var>> = vec![
+            Polynomial::from_str("x^2+-2").unwrap(),
+            Polynomial::from_str("y^2+-2").unwrap(),
+        ];
+        let mut primes = associated_primes(&generators, &[var!(x), var!(y)]).unwrap();
+        primes.sort_by_key(|p| p[0].to_string());
+        assert_eq!(
+            primes,
+            vec![
+                vec![
+                    Polynomial::from_str("x+-y").unwrap(),
+                    Polynomial::from_str("y^2+-2").unwrap()
+                ],
+                vec![
+                    Polynomial::from_str("x+y").unwrap(),
+                    Polynomial::from_str("y^2+-2").unwrap()
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_primary_decomposition_finite_field() {
+        // (x^3 - x^2, y^2) = (x^2, y^2) ∩ (x - 1, y^2)
+        let generators: Vec<Polynomial<Z7, Var>> = vec![
+            Polynomial::from_str("x^3+-x^2").unwrap(),
+            Polynomial::from_str("y^2").unwrap(),
+        ];
+        let components = primary_decomposition(&generators, &[var!(x), var!(y)]).unwrap();
+        assert_eq!(components.len(), 2);
+
+        let expected = [
+            (["x^2", "y^2"], ["x", "y"]),
+            (["x+-1", "y^2"], ["x+-1", "y"]),
+        ];
+        for (primary, prime) in expected {
+            let primary: Vec<Polynomial<Z7, Var>> = primary
+                .iter()
+                .map(|f| Polynomial::from_str(f).unwrap())
+                .collect();
+            let prime: Vec<Polynomial<Z7, Var>> = prime
+                .iter()
+                .map(|f| Polynomial::from_str(f).unwrap())
+                .collect();
+            assert!(components.contains(&PrimaryComponent { primary, prime }));
+        }
+
+        let unit: Vec<Polynomial<Z7, Var>> = vec![Polynomial::one()];
+        assert_eq!(
+            primary_decomposition(&unit, &[var!(x), var!(y)]).unwrap(),
+            vec![]
+        );
+    }
+}
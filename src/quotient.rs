@@ -0,0 +1,334 @@
+use std::{collections::BTreeSet, ops::Rem};
+
+use num::{One, Zero};
+
+use crate::{
+    order::{Lex, Order},
+    polynomial::{buchberger, normal_form, reduced_basis, Polynomial},
+    ring::{pow_ring, Field},
+    term::Term,
+    variable::{Var, Variable},
+    zero_dim::{coordinates, standard_index, standard_monomials},
+};
+
+/// The quotient ring `k[x]/I`, described by the reduced Gröbner basis of `I`.
+#[derive(Debug)]
+pub struct QuotientRing<R: Field, V: Variable = Var, O: Order = Lex> {
+    basis: Vec<Polynomial<R, V, O>>,
+    standard: Option<Vec<Term<V>>>,
+}
+
+impl<R: Field, V: Variable, O: Order> Clone for QuotientRing<R, V, O> {
+    fn clone(&self) -> Self {
+        Self {
+            basis: self.basis.clone(),
+            standard: self.standard.clone(),
+        }
+    }
+}
+
+impl<R: Field, V: Variable, O: Order> PartialEq for QuotientRing<R, V, O> {
+    fn eq(&self, other: &Self) -> bool {
+        self.basis == other.basis
+    }
+}
+
+impl<R: Field, V: Variable, O: Order> Eq for QuotientRing<R, V, O> {}
+
+impl<R: Field, V: Variable, O: Order> QuotientRing<R, V, O> {
+    pub fn new(generators: &[Polynomial<R, V, O>]) -> Self
+    where
+        R: Rem<R, Output = R>,
+    {
+        Self::from_basis(&buchberger(generators))
+    }
+
+    /// Build the quotient ring from a Gröbner basis of the ideal.
+    pub fn from_basis(basis: &[Polynomial<R, V, O>]) -> Self {
+        let basis = reduced_basis(basis);
+        let variables: Vec<V> = basis
+            .iter()
+            .flat_map(|g| {
+                g.iter()
+                    .flat_map(|m| m.term.exps.into_iter().map(|(v, _)| v))
+            })
+            .collect::<BTreeSet<V>>()
+            .into_iter()
+            .collect();
+        let standard = standard_monomials(&basis, &variables);
+        Self { basis, standard }
+    }
+
+    pub fn basis(&self) -> &[Polynomial<R, V, O>] {
+        &self.basis
+    }
+
+    /// Monomials spanning the quotient as a vector space, in increasing order,
+    /// or none when the ideal is not zero-dimensional in its variables.
+    pub fn standard_monomials(&self) -> Option<&[Term<V>]> {
+        self.standard.as_deref()
+    }
+
+    /// Dimension of the quotient as a vector space, when it is finite.
+    pub fn dimension(&self) -> Option<usize> {
+        self.standard.as_ref().map(|standard| standard.len())
+    }
+
+    pub fn reduce(&self, f: &Polynomial<R, V, O>) -> Polynomial<R, V, O> {
+        normal_form(f, &self.basis)
+    }
+
+    pub fn contains(&self, f: &Polynomial<R, V, O>) -> bool {
+        self.reduce(f).is_zero()
+    }
+}
+
+/// A zero-dimensional ideal fixed for a type, so that the elements of its
+/// quotient ring of dimension `N` need no reference to the ring. Declared
+/// with [`static_quotient_ring!`](crate::static_quotient_ring).
+pub trait StaticIdeal<const N: usize>: 'static {
+    type Field: Field;
+    type Order: Order;
+
+    /// The quotient ring, computed once.
+    fn ring() -> &'static QuotientRing<Self::Field, Var, Self::Order>;
+}
+
+/// An element of the quotient ring of a [`StaticIdeal`], stored as its
+/// coordinates in the standard monomials. It is `Copy` and a
+/// [`Ring`](crate::ring::Ring), so that it can be a coefficient itself.
+///
+/// Only zero-dimensional ideals whose dimension `N` is known at compile time
+/// are supported. For other ideals, compute with polynomials and their normal
+/// forms through [`QuotientRing::reduce`] and [`QuotientRing::contains`].
+pub struct QuotientElement<I: StaticIdeal<N>, const N: usize> {
+    coords: [I::Field; N],
+}
+
+impl<I: StaticIdeal<N>, const N: usize> Clone for QuotientElement<I, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I: StaticIdeal<N>, const N: usize> Copy for QuotientElement<I, N> {}
+
+impl<I: StaticIdeal<N>, const N: usize> PartialEq for QuotientElement<I, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coords == other.coords
+    }
+}
+
+impl<I: StaticIdeal<N>, const N: usize> Eq for QuotientElement<I, N> {}
+
+impl<I: StaticIdeal<N>, const N: usize> std::fmt::Debug for QuotientElement<I, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "QuotientElement({:?})", self.coords)
+    }
+}
+
+impl<I: StaticIdeal<N>, const N: usize> std::fmt::Display for QuotientElement<I, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let repr = self.repr();
+        // parenthesized to read as a single coefficient
+        if repr.monomials.len() > 1 {
+            write!(f, "({})", repr)
+        } else {
+            write!(f, "{}", repr)
+        }
+    }
+}
+
+impl<I: StaticIdeal<N>, const N: usize> Zero for QuotientElement<I, N> {
+    fn zero() -> Self {
+        Self {
+            coords: [I::Field::zero(); N],
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coords.iter().all(|c| c.is_zero())
+    }
+}
+
+impl<I: StaticIdeal<N>, const N: usize> One for QuotientElement<I, N> {
+    fn one() -> Self {
+        Self::new(&Polynomial::one())
+    }
+}
+
+impl<I: StaticIdeal<N>, const N: usize> QuotientElement<I, N> {
+    /// The class of `f`, given by its normal form.
+    pub fn new(f: &Polynomial<I::Field, Var, I::Order>) -> Self {
+        let ring = I::ring();
+        let standard = ring
+            .standard_monomials()
+            .expect("The ideal is not zero-dimensional");
+        let index = standard_index::<Var, I::Order>(standard);
+        let coords = coordinates(&ring.reduce(f), &index);
+        Self {
+            coords: coords
+                .try_into()
+                .expect("The quotient dimension does not match the type"),
+        }
+    }
+
+    /// Coordinates in [`QuotientRing::standard_monomials`].
+    pub fn coords(&self) -> &[I::Field; N] {
+        &self.coords
+    }
+
+    /// The normal form representing the element.
+    pub fn repr(&self) -> Polynomial<I::Field, Var, I::Order> {
+        let standard = I::ring()
+            .standard_monomials()
+            .expect("The ideal is not zero-dimensional");
+        standard
+            .iter()
+            .zip(self.coords.iter())
+            .filter(|(_, c)| !c.is_zero())
+            .map(|(term, &c)| term.clone() * c)
+            .collect()
+    }
+}
+
+#[inline]
+pub(crate) fn add_quo_quo<I: StaticIdeal<N>, const N: usize>(
+    left: QuotientElement<I, N>,
+    right: QuotientElement<I, N>,
+) -> QuotientElement<I, N> {
+    QuotientElement {
+        coords: std::array::from_fn(|i| left.coords[i] + right.coords[i]),
+    }
+}
+
+#[inline]
+pub(crate) fn sub_quo_quo<I: StaticIdeal<N>, const N: usize>(
+    left: QuotientElement<I, N>,
+    right: QuotientElement<I, N>,
+) -> QuotientElement<I, N> {
+    QuotientElement {
+        coords: std::array::from_fn(|i| left.coords[i] - right.coords[i]),
+    }
+}
+
+#[inline]
+pub(crate) fn mul_quo_quo<I: StaticIdeal<N>, const N: usize>(
+    left: QuotientElement<I, N>,
+    right: QuotientElement<I, N>,
+) -> QuotientElement<I, N> {
+    QuotientElement::new(&(&left.repr() * &right.repr()))
+}
+
+#[inline]
+pub(crate) fn pow_quo<I: StaticIdeal<N>, const N: usize>(
+    value: QuotientElement<I, N>,
+    exp: u32,
+) -> QuotientElement<I, N> {
+    pow_ring(value, exp as usize)
+}
+
+#[inline]
+pub(crate) fn neg_quo<I: StaticIdeal<N>, const N: usize>(
+    value: QuotientElement<I, N>,
+) -> QuotientElement<I, N> {
+    QuotientElement {
+        coords: value.coords.map(|c| -c),
+    }
+}
+
+/// Declare a quotient ring `k[x]/I` by a zero-dimensional ideal with a known
+/// dimension, as a marker type for the ideal and an alias for its elements.
+///
+/// `static_quotient_ring!(F49(Ideal: Z7, Lex, 2) = ["x^2+1"])` defines the
+/// field with 49 elements, whose elements are `F49 = QuotientElement<Ideal, 2>`.
+/// The generators are parsed and their Gröbner basis is computed on first use,
+/// panicking when the quotient does not have the given finite dimension.
+#[macro_export]
+macro_rules! static_quotient_ring {
+    (
+        $name:ident($ideal:ident: $field:ty, $order:ty, $dim:literal)
+            = [$($generator:literal),* $(,)?]
+    ) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct $ideal;
+
+        impl $crate::quotient::StaticIdeal<$dim> for $ideal {
+            type Field = $field;
+            type Order = $order;
+
+            fn ring(
+            ) -> &'static $crate::quotient::QuotientRing<$field, $crate::variable::Var, $order> {
+                static RING: std::sync::OnceLock<
+                    $crate::quotient::QuotientRing<$field, $crate::variable::Var, $order>,
+                > = std::sync::OnceLock::new();
+
+                RING.get_or_init(|| {
+                    let generators: Vec<
+                        $crate::polynomial::Polynomial<$field, $crate::variable::Var, $order>,
+                    > = [$($generator),*]
+                        .iter()
+                        .map(|f| f.parse().expect("Invalid generator"))
+                        .collect();
+                    let ring = $crate::quotient::QuotientRing::new(&generators);
+                    assert_eq!(
+                        ring.dimension(),
+                        Some($dim),
+                        "The quotient does not have the given dimension"
+                    );
+                    ring
+                })
+            }
+        }
+
+        pub type $name = $crate::quotient::QuotientElement<$ideal, $dim>;
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use num::traits::Pow;
+
+    use super::*;
+    use crate::{order::GradLex, static_prime_field, var};
+
+    static_prime_field!(Z7(7:u32));
+    static_quotient_ring!(Q(QIdeal: Z7, GradLex, 4) = ["x^2+-y", "y^2+-1"]);
+    static_quotient_ring!(F49(F49Ideal: Z7, Lex, 2) = ["x^2+1"]);
+
+    #[test]
+    fn test_quotient_arithmetic() {
+        let x = Q::new(&Polynomial::from_str("x").unwrap());
+        let y = Q::new(&Polynomial::from_str("y").unwrap());
+
+        assert_eq!(x * x, y);
+        assert!(x.pow(4).is_one());
+        assert_eq!(x.pow(3), x * y);
+        assert_eq!(x.pow(5), x);
+        assert!((y * y - Q::one()).is_zero());
+        assert_eq!(x + (-x), Q::zero());
+        assert_eq!((x * y).to_string(), "xy");
+
+        let ring = QIdeal::ring();
+        assert_eq!(ring.dimension(), Some(4));
+        assert!(ring.contains(&Polynomial::from_str("x^4+-1").unwrap()));
+        assert!(!ring.contains(&Polynomial::from_str("x+-1").unwrap()));
+    }
+
+    #[test]
+    fn test_quotient_coefficients() {
+        // y^2 + 1 = (y + i)(y - i) over the field with 49 elements
+        let i = F49::new(&Polynomial::from_str("x").unwrap());
+        let y: Polynomial<F49, Var> = [Term::from_iter([(var!(y), 1)]) * F49::one()]
+            .into_iter()
+            .collect();
+        let constant =
+            |c: F49| -> Polynomial<F49, Var> { [Term::default() * c].into_iter().collect() };
+
+        let product = (&y + &constant(i)) * (&y - &constant(i));
+        assert_eq!(product, &y * &y + constant(F49::one()));
+        assert_eq!(product.to_string(), "y² + 1");
+    }
+}
@@ -0,0 +1,237 @@
+use std::{collections::BTreeMap, ops::Rem};
+
+use num::{One, Zero};
+
+use crate::{
+    monomial::Monomial,
+    order::{Order, OrderedTerm},
+    polynomial::{buchberger, normal_form, reduced_basis, HeadMonomial, Polynomial},
+    ring::{Field, FiniteField, Rational},
+    term::Term,
+    univariate::UnivariatePolynomial,
+    variable::Variable,
+    zero_dim::is_zero_dimensional,
+};
+
+/// A field over which every irreducible polynomial is separable, so that
+/// square-free parts cut out the same roots in the algebraic closure.
+pub trait PerfectField: Field {
+    /// The product of the distinct monic irreducible factors of `f`.
+    fn square_free_part(f: &UnivariatePolynomial<Self>) -> UnivariatePolynomial<Self>;
+}
+
+impl<F: FiniteField> PerfectField for F {
+    fn square_free_part(f: &UnivariatePolynomial<Self>) -> UnivariatePolynomial<Self> {
+        f.square_free_factorization()
+            .into_iter()
+            .fold(UnivariatePolynomial::one(), |acc, (g, _)| acc * g)
+    }
+}
+
+/// Over the rationals the computations use [`Rational`], whose coefficients
+/// must stay within `i64`: larger systems panic on overflow and need
+/// [`groebner_over_q`](crate::modular::groebner_over_q) instead.
+impl PerfectField for Rational {
+    fn square_free_part(f: &UnivariatePolynomial<Self>) -> UnivariatePolynomial<Self> {
+        f.div_rem(&f.gcd(&f.derivative())).0.monic()
+    }
+}
+
+/// The variables of a system and one more variable that does not occur in
+/// it, for the Rabinowitsch trick.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Rabinowitsch<V> {
+    Original(V),
+    Fresh,
+}
+
+impl<V: Variable> std::fmt::Display for Rabinowitsch<V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rabinowitsch::Original(v) => write!(f, "{}", v),
+            Rabinowitsch::Fresh => write!(f, "t"),
+        }
+    }
+}
+
+impl<V: Variable> Variable for Rabinowitsch<V> {}
+
+/// Whether `f` lies in the radical of the ideal of `generators`, that is
+/// whether `f` vanishes on all the solutions over the algebraic closure.
+///
+/// With the Rabinowitsch trick, `f` is in the radical if and only if the
+/// ideal with the extra generator `1 - t * f` contains `1`, where `t` is a
+/// new variable.
+pub fn radical_contains<R, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    generators: &[Polynomial<R, V, O>],
+) -> bool
+where
+    R: Field + Rem<R, Output = R>,
+{
+    let lift = |g: &Polynomial<R, V, O>| -> Polynomial<R, Rabinowitsch<V>, O> {
+        g.iter()
+            .map(|m| Monomial {
+                coeff: m.coeff,
+                term: m
+                    .term
+                    .exps
+                    .iter()
+                    .map(|&(v, exp)| (Rabinowitsch::Original(v), exp))
+                    .collect(),
+            })
+            .collect()
+    };
+
+    let mut system: Vec<_> = generators.iter().map(lift).collect();
+    system.push(Polynomial::one() - &lift(f) * Term::from_iter([(Rabinowitsch::Fresh, 1)]));
+    buchberger(&system)
+        .iter()
+        .any(|g| !g.is_zero() && g.lead_term().exps.is_empty())
+}
+
+/// A normal form with the combination of powers of the eliminated variable
+/// it comes from.
+type EliminantRow<R, V, O> = (Polynomial<R, V, O>, Vec<R>);
+
+/// The minimal polynomial of `u` in `k[x]/I` for a zero-dimensional ideal
+/// `I` with Gröbner basis `basis`. For a variable `u`, it is the monic
+/// generator of the eliminant `I ∩ k[u]`.
+///
+/// The normal forms of the powers of `u` are put in echelon form until the
+/// first linear dependency.
+pub(crate) fn minimal_polynomial<R: Field, V: Variable, O: Order>(
+    basis: &[Polynomial<R, V, O>],
+    u: &Polynomial<R, V, O>,
+) -> UnivariatePolynomial<R> {
+    let mut rows: BTreeMap<OrderedTerm<V, O>, EliminantRow<R, V, O>> = Default::default();
+    let mut power = normal_form(&Polynomial::one(), basis);
+    for k in 0.. {
+        let mut f = power.clone();
+        let mut combination = vec![R::zero(); k + 1];
+        combination[k] = R::one();
+
+        while let Some((p, c)) = f.monomials.last_key_value().and_then(|(t, _)| rows.get(t)) {
+            let factor = f.lead_coeff() / p.lead_coeff();
+            f = f - p * (Term::default() * factor);
+            for (a, &b) in combination.iter_mut().zip(c.iter()) {
+                *a = *a - factor * b;
+            }
+        }
+        if f.is_zero() {
+            return UnivariatePolynomial::new(combination);
+        }
+        rows.insert(f.lead_term().into(), (f, combination));
+        power = normal_form(&(&power * u), basis);
+    }
+    unreachable!()
+}
+
+/// Reduced Gröbner basis of the radical of a zero-dimensional ideal, or none
+/// when the ideal of `generators` is not zero-dimensional in `variables`.
+///
+/// By Seidenberg's lemma, adding the square-free part of the eliminant of
+/// every variable gives the radical. Over [`Rational`] the intermediate
+/// coefficients must fit in `i64`, see [`PerfectField`].
+pub fn radical<R, V: Variable, O: Order>(
+    generators: &[Polynomial<R, V, O>],
+    variables: &[V],
+) -> Option<Vec<Polynomial<R, V, O>>>
+where
+    R: PerfectField + Rem<R, Output = R>,
+{
+    radical_of_basis(&buchberger(generators), variables)
+}
+
+/// [`radical`] of the ideal with the Gröbner basis `basis`.
+pub(crate) fn radical_of_basis<R, V: Variable, O: Order>(
+    basis: &[Polynomial<R, V, O>],
+    variables: &[V],
+) -> Option<Vec<Polynomial<R, V, O>>>
+where
+    R: PerfectField + Rem<R, Output = R>,
+{
+    if !is_zero_dimensional(basis, variables) {
+        return None;
+    }
+
+    let square_free = variables.iter().map(|&var| {
+        let eliminant = minimal_polynomial(basis, &UnivariatePolynomial::x().to_polynomial(var));
+        R::square_free_part(&eliminant).to_polynomial(var)
+    });
+    let system: Vec<Polynomial<R, V, O>> = basis.iter().cloned().chain(square_free).collect();
+    Some(reduced_basis(&buchberger(&system)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{static_prime_field, var, variable::Var};
+
+    static_prime_field!(Z7(7:u32));
+
+    #[test]
+    fn test_radical_contains() {
+        let generators: Vec<Polynomial<Rational, Var>> = vec![
+            Polynomial::from_str("x^2").unwrap(),
+            Polynomial::from_str("y^2").unwrap(),
+        ];
+
+        // (x + y)^3 lies in the ideal
+        assert!(radical_contains(
+            &Polynomial::from_str("x+y").unwrap(),
+            &generators
+        ));
+        assert!(!radical_contains(
+            &Polynomial::from_str("x+1").unwrap(),
+            &generators
+        ));
+
+        // every variable of Var may occur in the system
+        let generators: Vec<Polynomial<Rational, Var>> = vec![
+            Polynomial::from_str("t^2").unwrap(),
+            Polynomial::from_str("a+-1").unwrap(),
+        ];
+        assert!(radical_contains(
+            &Polynomial::from_str("at+t").unwrap(),
+            &generators
+        ));
+        assert!(!radical_contains(
+            &Polynomial::from_str("t+a").unwrap(),
+            &generators
+        ));
+    }
+
+    #[test]
+    fn test_radical() {
+        let generators: Vec<Polynomial<Rational, Var>> = vec![
+            Polynomial::from_str("x^2+-2x+1").unwrap(),
+            Polynomial::from_str("y^2+-xy").unwrap(),
+        ];
+        assert_eq!(
+            radical(&generators, &[var!(x), var!(y)]).unwrap(),
+            vec![
+                Polynomial::from_str("x+-1").unwrap(),
+                Polynomial::from_str("y^2+-y").unwrap()
+            ]
+        );
+
+        // x^7 - 1 = (x - 1)^7 in characteristic 7
+        let generators: Vec<Polynomial<Z7, Var>> = vec![
+            Polynomial::from_str("x^7+-1").unwrap(),
+            Polynomial::from_str("y^3").unwrap(),
+        ];
+        assert_eq!(
+            radical(&generators, &[var!(x), var!(y)]).unwrap(),
+            vec![
+                Polynomial::from_str("x+-1").unwrap(),
+                Polynomial::from_str("y").unwrap()
+            ]
+        );
+
+        let line: Vec<Polynomial<Z7, Var>> = vec![Polynomial::from_str("x+-y").unwrap()];
+        assert!(radical(&line, &[var!(x), var!(y)]).is_none());
+    }
+}
@@ -0,0 +1,261 @@
+use num::{BigInt, BigRational, Signed, ToPrimitive, Zero};
+
+use crate::{
+    dense::{derivative, div_rem, eval, gcd, trim, Dense},
+    ring::{Rational, Ring},
+    univariate::UnivariatePolynomial,
+};
+
+/// Coefficients that are exactly rational numbers, so that the real roots of
+/// their polynomials can be isolated.
+pub trait ExactRational {
+    fn to_big_rational(&self) -> BigRational;
+}
+
+impl ExactRational for Rational {
+    fn to_big_rational(&self) -> BigRational {
+        BigRational::new(self.numer().into(), self.denom().into())
+    }
+}
+
+impl ExactRational for BigRational {
+    fn to_big_rational(&self) -> BigRational {
+        self.clone()
+    }
+}
+
+impl ExactRational for BigInt {
+    fn to_big_rational(&self) -> BigRational {
+        BigRational::from_integer(self.clone())
+    }
+}
+
+impl ExactRational for i32 {
+    fn to_big_rational(&self) -> BigRational {
+        BigRational::from_integer((*self).into())
+    }
+}
+
+impl ExactRational for i64 {
+    fn to_big_rational(&self) -> BigRational {
+        BigRational::from_integer((*self).into())
+    }
+}
+
+type BigPoly = Dense<BigRational>;
+
+/// Sturm sequence of the square-free part of `f`.
+fn sturm_sequence(f: &[BigRational]) -> Vec<BigPoly> {
+    let square_free = div_rem(f, &gcd(f, &derivative(f))).0;
+    let mut sequence = vec![derivative(&square_free), square_free];
+    sequence.reverse();
+
+    while let [.., previous, last] = &sequence[..] {
+        if last.is_empty() {
+            sequence.pop();
+            break;
+        }
+        let (_, r) = div_rem(previous, last);
+        sequence.push(r.into_iter().map(|c| -c).collect());
+    }
+    sequence
+}
+
+fn sign_variations(sequence: &[BigPoly], x: &BigRational) -> usize {
+    let signs: Vec<bool> = sequence
+        .iter()
+        .map(|f| eval(f, x))
+        .filter(|value| !value.is_zero())
+        .map(|value| value.is_positive())
+        .collect();
+    signs.windows(2).filter(|pair| pair[0] != pair[1]).count()
+}
+
+/// Interval `(lower, upper]` containing exactly one real root of a
+/// polynomial, or the root itself when `lower == upper`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IsolatingInterval {
+    lower: BigRational,
+    upper: BigRational,
+    sturm: Vec<BigPoly>,
+}
+
+impl IsolatingInterval {
+    pub fn lower(&self) -> &BigRational {
+        &self.lower
+    }
+
+    pub fn upper(&self) -> &BigRational {
+        &self.upper
+    }
+
+    pub fn width(&self) -> BigRational {
+        &self.upper - &self.lower
+    }
+
+    /// Whether the root is known exactly, being rational.
+    pub fn is_exact(&self) -> bool {
+        self.lower == self.upper
+    }
+
+    /// Bisect until the width is at most `precision`, which must be
+    /// positive.
+    pub fn refine(&mut self, precision: &BigRational) {
+        assert!(precision.is_positive(), "The precision must be positive");
+        while &self.width() > precision {
+            let mid = (&self.lower + &self.upper) / BigInt::from(2);
+            if eval(&self.sturm[0], &mid).is_zero() {
+                self.lower = mid.clone();
+                self.upper = mid;
+            } else if sign_variations(&self.sturm, &self.lower) - sign_variations(&self.sturm, &mid)
+                == 1
+            {
+                self.upper = mid;
+            } else {
+                self.lower = mid;
+            }
+        }
+    }
+
+    /// Midpoint of the interval as a float.
+    pub fn approximation(&self) -> f64 {
+        ((&self.lower + &self.upper) / BigInt::from(2))
+            .to_f64()
+            .unwrap_or(f64::NAN)
+    }
+}
+
+fn isolate(
+    sturm: &[BigPoly],
+    lower: BigRational,
+    upper: BigRational,
+    count: usize,
+    intervals: &mut Vec<IsolatingInterval>,
+) {
+    match count {
+        0 => {}
+        1 => {
+            let lower = if eval(&sturm[0], &upper).is_zero() {
+                upper.clone()
+            } else {
+                lower
+            };
+            intervals.push(IsolatingInterval {
+                lower,
+                upper,
+                sturm: sturm.to_vec(),
+            });
+        }
+        _ => {
+            let mid = (&lower + &upper) / BigInt::from(2);
+            let left = sign_variations(sturm, &lower) - sign_variations(sturm, &mid);
+            isolate(sturm, lower, mid.clone(), left, intervals);
+            isolate(sturm, mid, upper, count - left, intervals);
+        }
+    }
+}
+
+/// Isolating intervals of the distinct real roots of `coeffs`, given from the
+/// constant term up, in increasing order. The coefficients are integers or
+/// rationals, of arbitrary precision or not.
+///
+/// Roots are counted with Sturm sequences of the square-free part in
+/// `(-M, M]`, where `M` is Cauchy's bound, and the interval is bisected until
+/// every part holds at most one root.
+pub fn isolate_real_roots<C: ExactRational>(coeffs: &[C]) -> Vec<IsolatingInterval> {
+    let f = trim(coeffs.iter().map(|c| c.to_big_rational()).collect());
+    assert!(!f.is_empty(), "The zero polynomial vanishes everywhere");
+    let sturm = sturm_sequence(&f);
+
+    let lead = f.last().unwrap();
+    let bound = f
+        .iter()
+        .map(|c| (c / lead).abs())
+        .fold(BigRational::zero(), |acc, c| if c > acc { c } else { acc })
+        + BigRational::from_integer(BigInt::from(1));
+
+    let count = sign_variations(&sturm, &-bound.clone()) - sign_variations(&sturm, &bound);
+    let mut intervals: Vec<IsolatingInterval> = Default::default();
+    isolate(&sturm, -bound.clone(), bound, count, &mut intervals);
+    intervals
+}
+
+impl<R: Ring + ExactRational> UnivariatePolynomial<R> {
+    /// Isolating intervals of the distinct real roots, see
+    /// [`isolate_real_roots`].
+    pub fn real_roots(&self) -> Vec<IsolatingInterval> {
+        isolate_real_roots(self.coeffs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dense::mul;
+
+    fn rational(numer: i64, denom: i64) -> BigRational {
+        BigRational::new(numer.into(), denom.into())
+    }
+
+    #[test]
+    fn test_isolate_sqrt_two() {
+        let f = UnivariatePolynomial::new(vec![
+            Rational::from(-2),
+            Rational::from(0),
+            Rational::from(1),
+        ]);
+        let mut roots = f.real_roots();
+        assert_eq!(roots.len(), 2);
+
+        let precision = rational(1, 1_000_000);
+        roots[1].refine(&precision);
+        let (lower, upper) = (roots[1].lower(), roots[1].upper());
+        assert!(roots[1].width() <= precision);
+        assert!(lower * lower < rational(2, 1) && rational(2, 1) < upper * upper);
+        assert!((roots[1].approximation() - 2f64.sqrt()).abs() < 1e-6);
+        assert!(roots[0].upper() <= &rational(0, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "The precision must be positive")]
+    fn test_refine_zero_precision() {
+        let mut roots = isolate_real_roots(&[rational(-2, 1), rational(0, 1), rational(1, 1)]);
+        roots[0].refine(&rational(0, 1));
+    }
+
+    #[test]
+    fn test_isolate_multiple_roots() {
+        // (x - 1/2)^2 (x + 3) (x^2 + 1)
+        let f = [rational(-1, 2), rational(1, 1)];
+        let g = [rational(3, 1), rational(1, 1)];
+        let h = [rational(1, 1), rational(0, 1), rational(1, 1)];
+        let product = [&f[..], &f, &g, &h]
+            .iter()
+            .fold(vec![rational(1, 1)], |acc, factor| mul(&acc, factor));
+
+        let mut roots = isolate_real_roots(&product);
+        assert_eq!(roots.len(), 2);
+        for root in roots.iter_mut() {
+            root.refine(&rational(1, 1000));
+        }
+        assert!(roots[0].lower() <= &rational(-3, 1) && roots[0].upper() >= &rational(-3, 1));
+        assert!(roots[1].lower() <= &rational(1, 2) && roots[1].upper() >= &rational(1, 2));
+    }
+
+    #[test]
+    fn test_isolate_integer_coefficients() {
+        // x^2 - 2 has the same roots with every kind of coefficient
+        let f: UnivariatePolynomial<i64> = UnivariatePolynomial::new(vec![-2, 0, 1]);
+        let expected = isolate_real_roots(&[rational(-2, 1), rational(0, 1), rational(1, 1)]);
+        assert_eq!(f.real_roots(), expected);
+        let big: Vec<BigInt> = [-2, 0, 1].into_iter().map(BigInt::from).collect();
+        assert_eq!(isolate_real_roots(&big), expected);
+
+        // coefficients beyond i64
+        let huge = BigInt::from(i64::MAX) * BigInt::from(i64::MAX);
+        let roots = isolate_real_roots(&[-huge.clone(), BigInt::zero(), BigInt::from(1)]);
+        assert_eq!(roots.len(), 2);
+        assert!(roots[1].lower() < &BigRational::from_integer(BigInt::from(i64::MAX)));
+        assert!(roots[1].upper() >= &BigRational::from_integer(BigInt::from(i64::MAX)));
+    }
+}
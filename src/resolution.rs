@@ -0,0 +1,282 @@
+use crate::{
+    module::{kernel, module_buchberger, module_normal_form, ModuleElement},
+    order::{Order, TermOverPosition},
+    polynomial::{is_homogeneous, Polynomial},
+    ring::Field,
+    term::{Degree, Term},
+    variable::Variable,
+};
+
+/// A graded free resolution `0 <- F_0 <- F_1 <- ... <- F_n <- 0` of
+/// `R[x]/I`, with `F_0 = R[x]`.
+#[derive(Debug)]
+pub struct FreeResolution<R: Field, V: Variable, O: Order> {
+    maps: Vec<Vec<ModuleElement<R, V, O>>>,
+    degrees: Vec<Vec<usize>>,
+}
+
+/// The graded Betti numbers `b_ij` of a [`FreeResolution`], the number of
+/// generators of degree `j` of `F_i`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BettiTable {
+    degrees: Vec<Vec<usize>>,
+}
+
+impl<R: Field, V: Variable, O: Order> FreeResolution<R, V, O> {
+    /// The differential `F_(i+1) -> F_i`, as the images of the basis of
+    /// `F_(i+1)`.
+    pub fn map(&self, i: usize) -> &[ModuleElement<R, V, O>] {
+        &self.maps[i]
+    }
+
+    /// The degrees of the basis of `F_i`.
+    pub fn degrees(&self, i: usize) -> &[usize] {
+        &self.degrees[i]
+    }
+
+    /// The length `n` of the resolution, the index of its last nonzero
+    /// module.
+    pub fn length(&self) -> usize {
+        self.maps.len()
+    }
+
+    pub fn betti(&self) -> BettiTable {
+        BettiTable {
+            degrees: self.degrees.clone(),
+        }
+    }
+}
+
+impl BettiTable {
+    /// The Betti number `b_ij`.
+    pub fn get(&self, i: usize, j: usize) -> usize {
+        self.degrees
+            .get(i)
+            .map_or(0, |degrees| degrees.iter().filter(|&&d| d == j).count())
+    }
+
+    /// The rank of `F_i`.
+    pub fn total(&self, i: usize) -> usize {
+        self.degrees.get(i).map_or(0, |degrees| degrees.len())
+    }
+
+    pub fn length(&self) -> usize {
+        self.degrees.len() - 1
+    }
+
+    /// The regularity, the last row of the table.
+    pub fn regularity(&self) -> usize {
+        self.rows().max().unwrap_or(0).max(0) as usize
+    }
+
+    /// The rows `j - i` of the nonzero entries `b_ij`, negative for the unit
+    /// ideal whose generator has degree `0` in `F_1`.
+    fn rows(&self) -> impl Iterator<Item = isize> + '_ {
+        self.degrees
+            .iter()
+            .enumerate()
+            .flat_map(|(i, degrees)| degrees.iter().map(move |&d| d as isize - i as isize))
+    }
+}
+
+/// Macaulay2 layout, the entry `b_(i,i+r)` in column `i` and row `r`, with
+/// dots for zeros.
+impl std::fmt::Display for BettiTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let first = self.rows().min().unwrap_or(0);
+        let rows: Vec<Vec<String>> = (first..=self.regularity() as isize)
+            .map(|r| {
+                (0..=self.length())
+                    .map(
+                        |i| match usize::try_from(i as isize + r).map(|j| self.get(i, j)) {
+                            Ok(0) | Err(_) => ".".to_string(),
+                            Ok(b) => b.to_string(),
+                        },
+                    )
+                    .collect()
+            })
+            .collect();
+        let totals: Vec<String> = (0..=self.length())
+            .map(|i| self.total(i).to_string())
+            .collect();
+        let widths: Vec<usize> = (0..=self.length())
+            .map(|i| {
+                rows.iter()
+                    .map(|row| row[i].len())
+                    .chain([totals[i].len(), i.to_string().len()])
+                    .max()
+                    .unwrap()
+            })
+            .collect();
+
+        let mut line = |label: &str, entries: Vec<String>| {
+            write!(f, "{:>6}", label)?;
+            for (entry, width) in entries.iter().zip(widths.iter()) {
+                write!(f, " {:>width$}", entry, width = width)?;
+            }
+            writeln!(f)
+        };
+        line("", (0..=self.length()).map(|i| i.to_string()).collect())?;
+        line("total:", totals)?;
+        for (r, row) in (first..).zip(rows) {
+            line(&format!("{}:", r), row)?;
+        }
+        Ok(())
+    }
+}
+
+/// The degree of a homogeneous element of a free module whose basis has the
+/// given degrees.
+fn degree<R: Field, V: Variable, O: Order>(f: &ModuleElement<R, V, O>, degrees: &[usize]) -> usize {
+    f.components()
+        .iter()
+        .zip(degrees.iter())
+        .find(|(a, _)| !a.monomials.is_empty())
+        .map_or(0, |(a, d)| a.deg() + d)
+}
+
+/// A minimal generating set of the submodule generated by the homogeneous
+/// `elements`, found by dropping the elements generated by those of lower
+/// or equal degree.
+///
+/// The elements are processed degree by degree against a single Gröbner
+/// basis of those kept in lower degrees. The normal forms of the elements of
+/// one degree are then compared by linear algebra, since in equal degrees
+/// they can only combine with constant coefficients.
+fn minimize<R: Field, V: Variable, O: Order>(
+    elements: Vec<ModuleElement<R, V, O>>,
+    degrees: &[usize],
+) -> Vec<ModuleElement<R, V, O>> {
+    let mut elements = elements;
+    elements.sort_by_key(|f| degree(f, degrees));
+
+    let mut minimal: Vec<ModuleElement<R, V, O>> = Default::default();
+    let mut basis: Vec<ModuleElement<R, V, O>> = Default::default();
+    for group in elements.chunk_by(|f, g| degree(f, degrees) == degree(g, degrees)) {
+        let kept = minimal.len();
+        let mut rows: Vec<ModuleElement<R, V, O>> = Default::default();
+        for f in group {
+            let mut r = module_normal_form::<R, V, O, TermOverPosition>(f, &basis);
+            while let Some((i, term, coeff)) = r.lead::<TermOverPosition>() {
+                let Some((row, row_coeff)) = rows.iter().find_map(|row| {
+                    let (j, row_term, row_coeff) = row.lead::<TermOverPosition>()?;
+                    (i == j && term == row_term).then_some((row, row_coeff))
+                }) else {
+                    break;
+                };
+                let factor: Polynomial<R, V, O> = [Term::default() * (coeff / row_coeff)]
+                    .into_iter()
+                    .collect();
+                r = r - &factor * row;
+            }
+            if !r.is_zero() {
+                rows.push(r);
+                minimal.push(f.clone());
+            }
+        }
+        if minimal.len() > kept {
+            basis = module_buchberger::<R, V, O, TermOverPosition>(&minimal);
+        }
+    }
+    minimal
+}
+
+/// Minimal graded free resolution of `R[x]/I` for the ideal `I` of the
+/// homogeneous `generators`.
+///
+/// Each module is the kernel of the previous differential, minimized degree
+/// by degree, so that the ranks are the graded Betti numbers of `R[x]/I`.
+/// Hilbert's syzygy theorem bounds the length by the number of variables.
+pub fn minimal_free_resolution<R: Field, V: Variable, O: Order>(
+    generators: &[Polynomial<R, V, O>],
+) -> FreeResolution<R, V, O> {
+    assert!(
+        generators.iter().all(is_homogeneous),
+        "The generators must be homogeneous"
+    );
+
+    let generators = generators
+        .iter()
+        .filter(|f| !f.monomials.is_empty())
+        .map(|f| ModuleElement::new(vec![f.clone()]))
+        .collect();
+    let mut resolution = FreeResolution {
+        maps: Default::default(),
+        degrees: vec![vec![0]],
+    };
+
+    let mut next = minimize(generators, &resolution.degrees[0]);
+    while !next.is_empty() {
+        let target = resolution.degrees.last().unwrap();
+        let degrees = next.iter().map(|f| degree(f, target)).collect();
+
+        let syzygies = kernel(&next);
+        resolution.maps.push(next);
+        resolution.degrees.push(degrees);
+        next = minimize(syzygies, resolution.degrees.last().unwrap());
+    }
+    resolution
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{ring::Rational, variable::Var};
+
+    #[test]
+    fn test_resolution_twisted_cubic() {
+        let generators: Vec<Polynomial<Rational, Var>> = vec![
+            Polynomial::from_str("wy+-x^2").unwrap(),
+            Polynomial::from_str("xz+-y^2").unwrap(),
+            Polynomial::from_str("wz+-xy").unwrap(),
+        ];
+        let resolution = minimal_free_resolution(&generators);
+        assert_eq!(resolution.length(), 2);
+
+        // the differentials compose to zero
+        for syzygy in resolution.map(1) {
+            let image: Vec<Polynomial<Rational, Var>> =
+                resolution.map(0).iter().map(|f| f[0].clone()).collect();
+            assert!(syzygy.dot(&image).monomials.is_empty());
+        }
+
+        let betti = resolution.betti();
+        assert_eq!(betti.get(1, 2), 3);
+        assert_eq!(betti.get(2, 3), 2);
+        assert_eq!(
+            betti.to_string(),
+            "       0 1 2\ntotal: 1 3 2\n    0: 1 . .\n    1: . 3 2\n"
+        );
+    }
+
+    #[test]
+    fn test_resolution_unit_ideal() {
+        let generators: Vec<Polynomial<Rational, Var>> = vec![
+            Polynomial::from_str("1").unwrap(),
+            Polynomial::from_str("x").unwrap(),
+        ];
+        let betti = minimal_free_resolution(&generators).betti();
+        assert_eq!(betti.regularity(), 0);
+        assert_eq!(
+            betti.to_string(),
+            "       0 1\ntotal: 1 1\n   -1: . 1\n    0: 1 .\n"
+        );
+    }
+
+    #[test]
+    fn test_resolution_koszul() {
+        let generators: Vec<Polynomial<Rational, Var>> = vec![
+            Polynomial::from_str("x").unwrap(),
+            Polynomial::from_str("y").unwrap(),
+            Polynomial::from_str("z").unwrap(),
+            Polynomial::from_str("x+y").unwrap(),
+        ];
+        let betti = minimal_free_resolution(&generators).betti();
+        assert_eq!(
+            betti.to_string(),
+            "       0 1 2 3\ntotal: 1 3 3 1\n    0: 1 3 3 1\n"
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use num::{traits::Pow, One, Zero};
+
+use crate::{
+    gcd::{coefficients, div_exact, from_coefficients, pseudo_remainder, Coefficients},
+    order::Order,
+    polynomial::Polynomial,
+    ring::GcdDomain,
+    variable::Variable,
+};
+
+/// One step of the subresultant algorithm, replacing `(a, b)` with `b` and
+/// the pseudo-remainder of `a` by `b` divided by `g * h^delta`, and updating
+/// `g` and `h`. Returns false when the remainder is zero.
+fn subresultant_step<R: GcdDomain, V: Variable, O: Order>(
+    a: &mut Coefficients<R, V, O>,
+    b: &mut Coefficients<R, V, O>,
+    g: &mut Polynomial<R, V, O>,
+    h: &mut Polynomial<R, V, O>,
+) -> bool {
+    let delta = (a.len() - b.len()) as u32;
+    let remainder = pseudo_remainder(a, b);
+    if remainder.is_empty() {
+        return false;
+    }
+
+    let divisor = &*g * (&*h).pow(delta);
+    let remainder = remainder
+        .iter()
+        .map(|c| div_exact(c, &divisor).unwrap())
+        .collect();
+    *a = std::mem::replace(b, remainder);
+
+    *g = a.last().unwrap().clone();
+    if delta > 0 {
+        *h = div_exact(&(&*g).pow(delta), &(&*h).pow(delta - 1)).unwrap();
+    }
+    true
+}
+
+/// Subresultant pseudo-remainder sequence of `f` and `g` with respect to
+/// `var`, starting with the one of larger degree and ending with the last
+/// nonzero remainder.
+///
+/// The remainders are divided by the known factors of their content, so
+/// that the coefficients grow no faster than the subresultants.
+pub fn subresultant_prs<R: GcdDomain, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    g: &Polynomial<R, V, O>,
+    var: V,
+) -> Vec<Polynomial<R, V, O>> {
+    let (mut a, mut b) = (coefficients(f, var), coefficients(g, var));
+    if a.len() < b.len() {
+        (a, b) = (b, a);
+    }
+    let mut sequence: Vec<Polynomial<R, V, O>> = [&a, &b]
+        .into_iter()
+        .filter(|c| !c.is_empty())
+        .map(|c| from_coefficients(c, var))
+        .collect();
+
+    let (mut lead, mut h) = (Polynomial::one(), Polynomial::one());
+    while b.len() > 1 && subresultant_step(&mut a, &mut b, &mut lead, &mut h) {
+        sequence.push(from_coefficients(&b, var));
+    }
+    sequence
+}
+
+/// Resultant of `f` and `g` with respect to `var`, a polynomial in the
+/// remaining variables that vanishes exactly where `f` and `g` have a common
+/// root in `var`, or a common vanishing lead coefficient.
+///
+/// Computed from the subresultant sequence, following Cohen's algorithm
+/// 3.3.7, with only exact divisions in the coefficient ring.
+pub fn resultant<R: GcdDomain, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    g: &Polynomial<R, V, O>,
+    var: V,
+) -> Polynomial<R, V, O> {
+    if f.is_zero() || g.is_zero() {
+        return Polynomial::zero();
+    }
+
+    let (mut a, mut b) = (coefficients(f, var), coefficients(g, var));
+    // a polynomial of odd degree has an even number of coefficients
+    let odd_degree = |c: &Coefficients<R, V, O>| c.len().is_multiple_of(2);
+    let mut negate = false;
+    if a.len() < b.len() {
+        negate = odd_degree(&a) && odd_degree(&b);
+        (a, b) = (b, a);
+    }
+
+    let (mut lead, mut h) = (Polynomial::one(), Polynomial::one());
+    while b.len() > 1 {
+        if odd_degree(&a) && odd_degree(&b) {
+            negate = !negate;
+        }
+        if !subresultant_step(&mut a, &mut b, &mut lead, &mut h) {
+            return Polynomial::zero();
+        }
+    }
+
+    let degree = (a.len() - 1) as u32;
+    if degree == 0 {
+        return Polynomial::one();
+    }
+    let result = div_exact(&(&b[0]).pow(degree), &(&h).pow(degree - 1)).unwrap();
+    if negate {
+        Polynomial::zero() - result
+    } else {
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{static_prime_field, var, variable::Var};
+
+    static_prime_field!(Z7(7:u32));
+
+    #[test]
+    fn test_resultant_integers() {
+        // the resultant of x^3 + px + q and its derivative is 4p^3 + 27q^2
+        let f: Polynomial<i64, Var> = Polynomial::from_str("x^3+xy+1").unwrap();
+        let g: Polynomial<i64, Var> = Polynomial::from_str("3x^2+y").unwrap();
+        assert_eq!(
+            resultant(&f, &g, var!(x)),
+            Polynomial::from_str("4y^3+27").unwrap()
+        );
+
+        // both degrees odd, the resultant of x - 2 and f is f(2)
+        let h: Polynomial<i64, Var> = Polynomial::from_str("x+-2").unwrap();
+        assert_eq!(
+            resultant(&h, &f, var!(x)),
+            Polynomial::from_str("2y+9").unwrap()
+        );
+        assert_eq!(
+            resultant(&f, &h, var!(x)),
+            Polynomial::from_str("-2y+-9").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_subresultant_prs() {
+        // the circle and the diagonal meet where 2y^2 = 1
+        let f: Polynomial<Z7, Var> = Polynomial::from_str("x^2+y^2+-1").unwrap();
+        let g: Polynomial<Z7, Var> = Polynomial::from_str("x+-y").unwrap();
+        assert_eq!(
+            resultant(&f, &g, var!(x)),
+            Polynomial::from_str("2y^2+-1").unwrap()
+        );
+
+        // (x - y)(x + 1) and (x - y)(x + 2) have a common factor
+        let f: Polynomial<i64, Var> = Polynomial::from_str("x^2+-xy+x+-y").unwrap();
+        let g: Polynomial<i64, Var> = Polynomial::from_str("x^2+-xy+2x+-2y").unwrap();
+        assert!(resultant(&f, &g, var!(x)).is_zero());
+
+        let sequence = subresultant_prs(&f, &g, var!(x));
+        assert_eq!(sequence.len(), 3);
+        assert!(div_exact(&sequence[2], &Polynomial::from_str("x+-y").unwrap()).is_some());
+    }
+}
@@ -1,13 +1,11 @@
+use std::cell::Cell;
 use std::fmt::{Debug, Display};
-use std::ops::{Add, Deref, Mul, Neg, Sub};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 use std::str::FromStr;
 
-use num::{Integer, One, Zero};
-
-use crate::{
-    monomial::{mul_ring_mono, mul_ring_term, Monomial},
-    term::Term,
-    variable::Variable,
+use num::{
+    rational::Ratio, BigInt, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Integer, One,
+    ToPrimitive, Zero,
 };
 
 pub trait Ring:
@@ -43,6 +41,127 @@ impl<
 {
 }
 
+/// `n * c` computed with additions only, valid in any characteristic.
+pub(crate) fn mul_by_integer<R: Ring>(c: R, mut n: usize) -> R {
+    let mut result = R::zero();
+    let mut base = c;
+    while n > 0 {
+        if n % 2 == 1 {
+            result = result + base;
+        }
+        base = base + base;
+        n /= 2;
+    }
+    result
+}
+
+/// `c^exp` computed by repeated squaring.
+pub(crate) fn pow_ring<R: Ring>(c: R, mut exp: usize) -> R {
+    let mut result = R::one();
+    let mut base = c;
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = result * base;
+        }
+        base = base * base;
+        exp /= 2;
+    }
+    result
+}
+
+/// A ring in which every nonzero element has a multiplicative inverse.
+pub trait Field: Ring + Div<Self, Output = Self> {
+    fn inv(self) -> Self;
+}
+
+/// A prime field, whose elements are the residues modulo its characteristic.
+pub trait FiniteField: Field {
+    fn characteristic() -> u64;
+
+    fn from_u64(value: u64) -> Self;
+
+    /// The representative of the element in `0..characteristic`.
+    fn to_u64(self) -> u64;
+}
+
+/// A ring with greatest common divisors, such as a field or the integers.
+pub trait GcdDomain: Ring {
+    /// Greatest common divisor, normalized so that `unit` of it is one.
+    fn gcd(self, other: Self) -> Self;
+
+    /// `self / other`, assuming `other` divides `self`.
+    fn exact_div(self, other: Self) -> Self;
+
+    /// The unit dividing `self` into its normal form, one for zero.
+    fn unit(self) -> Self;
+
+    /// The value as an arbitrary precision integer, for the integer types,
+    /// whose polynomial gcds are computed modulo primes to avoid overflow.
+    fn to_integer(self) -> Option<BigInt> {
+        None
+    }
+
+    /// The integer `value`, when the ring holds it.
+    fn from_integer(_value: &BigInt) -> Option<Self> {
+        None
+    }
+}
+
+impl<F: Field> GcdDomain for F {
+    fn gcd(self, other: Self) -> Self {
+        if self.is_zero() && other.is_zero() {
+            F::zero()
+        } else {
+            F::one()
+        }
+    }
+
+    fn exact_div(self, other: Self) -> Self {
+        self / other
+    }
+
+    fn unit(self) -> Self {
+        if self.is_zero() {
+            F::one()
+        } else {
+            self
+        }
+    }
+}
+
+macro_rules! impl_integer_gcd_domain {
+    ( $type:ty ) => {
+        impl GcdDomain for $type {
+            fn gcd(self, other: Self) -> Self {
+                Integer::gcd(&self, &other)
+            }
+
+            fn exact_div(self, other: Self) -> Self {
+                self / other
+            }
+
+            fn unit(self) -> Self {
+                if self < 0 {
+                    -1
+                } else {
+                    1
+                }
+            }
+
+            fn to_integer(self) -> Option<BigInt> {
+                Some(BigInt::from(self))
+            }
+
+            fn from_integer(value: &BigInt) -> Option<Self> {
+                <$type>::try_from(value).ok()
+            }
+        }
+    };
+}
+
+impl_integer_gcd_domain!(i32);
+impl_integer_gcd_domain!(i64);
+
 pub trait Mod<T: Integer> {
     const N: T;
 }
@@ -50,21 +169,21 @@ pub trait Mod<T: Integer> {
 #[macro_export]
 macro_rules! impl_ring_term_mul {
     ($ring:tt) => {
-        impl<V: Variable> Mul<Term<V>> for $ring {
-            type Output = Monomial<$ring, V>;
+        impl<V: $crate::variable::Variable> std::ops::Mul<$crate::term::Term<V>> for $ring {
+            type Output = $crate::monomial::Monomial<$ring, V>;
 
             #[inline]
-            fn mul(self, rhs: Term<V>) -> Self::Output {
-                mul_ring_term(self, rhs)
+            fn mul(self, rhs: $crate::term::Term<V>) -> Self::Output {
+                $crate::monomial::mul_ring_term(self, rhs)
             }
         }
 
-        impl<V: Variable> Mul<&Term<V>> for $ring {
-            type Output = Monomial<$ring, V>;
+        impl<V: $crate::variable::Variable> std::ops::Mul<&$crate::term::Term<V>> for $ring {
+            type Output = $crate::monomial::Monomial<$ring, V>;
 
             #[inline]
-            fn mul(self, rhs: &Term<V>) -> Self::Output {
-                mul_ring_term(self, rhs.clone())
+            fn mul(self, rhs: &$crate::term::Term<V>) -> Self::Output {
+                $crate::monomial::mul_ring_term(self, rhs.clone())
             }
         }
     };
@@ -73,21 +192,25 @@ macro_rules! impl_ring_term_mul {
 #[macro_export]
 macro_rules! impl_ring_mono_mul {
     ($ring:tt) => {
-        impl<V: Variable> Mul<Monomial<$ring, V>> for $ring {
-            type Output = Monomial<$ring, V>;
+        impl<V: $crate::variable::Variable> std::ops::Mul<$crate::monomial::Monomial<$ring, V>>
+            for $ring
+        {
+            type Output = $crate::monomial::Monomial<$ring, V>;
 
             #[inline]
-            fn mul(self, rhs: Monomial<$ring, V>) -> Self::Output {
-                mul_ring_mono(self, rhs)
+            fn mul(self, rhs: $crate::monomial::Monomial<$ring, V>) -> Self::Output {
+                $crate::monomial::mul_ring_mono(self, rhs)
             }
         }
 
-        impl<V: Variable> Mul<&Monomial<$ring, V>> for $ring {
-            type Output = Monomial<$ring, V>;
+        impl<V: $crate::variable::Variable> std::ops::Mul<&$crate::monomial::Monomial<$ring, V>>
+            for $ring
+        {
+            type Output = $crate::monomial::Monomial<$ring, V>;
 
             #[inline]
-            fn mul(self, rhs: &Monomial<$ring, V>) -> Self::Output {
-                mul_ring_mono(self, rhs.clone())
+            fn mul(self, rhs: &$crate::monomial::Monomial<$ring, V>) -> Self::Output {
+                $crate::monomial::mul_ring_mono(self, rhs.clone())
             }
         }
     };
@@ -99,16 +222,19 @@ macro_rules! static_finit_ring {
         #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
         pub struct $name($type);
 
-        impl Mod<$type> for $name {
+        impl $crate::ring::Mod<$type> for $name {
             const N: $type = $mod;
         }
 
-        impl FromStr for $name {
+        impl std::str::FromStr for $name {
             type Err = String;
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
+                if let Some(abs) = s.strip_prefix('-') {
+                    return abs.parse::<$name>().map(|value| -value);
+                }
                 if let Ok(value) = s.parse::<$type>() {
-                    if value < $name::N {
+                    if value < <$name as $crate::ring::Mod<$type>>::N {
                         return Ok($name(value));
                     }
                 }
@@ -116,7 +242,7 @@ macro_rules! static_finit_ring {
             }
         }
 
-        impl Deref for $name {
+        impl std::ops::Deref for $name {
             type Target = $type;
 
             fn deref(&self) -> &Self::Target {
@@ -124,87 +250,536 @@ macro_rules! static_finit_ring {
             }
         }
 
-        impl Into<$type> for $name {
-            fn into(self) -> $type {
-                self.0
+        impl From<$name> for $type {
+            fn from(value: $name) -> $type {
+                value.0
             }
         }
 
-        impl Display for $name {
+        impl std::fmt::Display for $name {
             #[inline]
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 write!(f, "{}", self.0)
             }
         }
 
-        impl Zero for $name {
+        impl $crate::num::Zero for $name {
             fn zero() -> Self {
-                Self($type::zero())
+                Self(<$type as $crate::num::Zero>::zero())
             }
 
             fn is_zero(&self) -> bool {
-                self.0.is_zero()
+                $crate::num::Zero::is_zero(&self.0)
             }
         }
 
-        impl One for $name {
+        impl $crate::num::One for $name {
             fn one() -> Self {
-                Self($type::one())
+                Self(<$type as $crate::num::One>::one())
             }
 
             fn is_one(&self) -> bool {
-                self.0.is_one()
+                $crate::num::One::is_one(&self.0)
             }
         }
 
-        impl Add<$name> for $name {
+        impl std::ops::Add<$name> for $name {
             type Output = $name;
 
             #[inline]
             fn add(self, rhs: $name) -> Self::Output {
-                Self((self.0 + rhs.0) % Self::N)
+                let n = <Self as $crate::ring::Mod<$type>>::N as u128;
+                Self(((self.0 as u128 + rhs.0 as u128) % n) as $type)
             }
         }
 
-        impl Neg for $name {
+        impl std::ops::Neg for $name {
             type Output = $name;
 
             #[inline]
             fn neg(self) -> Self::Output {
-                Self(Self::N - self.0)
+                Self(
+                    (<Self as $crate::ring::Mod<$type>>::N - self.0)
+                        % <Self as $crate::ring::Mod<$type>>::N,
+                )
             }
         }
 
-        impl Sub<$name> for $name {
+        impl std::ops::Sub<$name> for $name {
             type Output = $name;
 
             #[inline]
             fn sub(self, rhs: $name) -> Self::Output {
-                self.add(rhs.neg())
+                self + (-rhs)
             }
         }
 
-        impl Mul<$name> for $name {
+        impl std::ops::Mul<$name> for $name {
             type Output = $name;
 
             #[inline]
             fn mul(self, rhs: $name) -> Self::Output {
-                Self((self.0 * rhs.0) % Self::N)
+                let n = <Self as $crate::ring::Mod<$type>>::N as u128;
+                Self(((self.0 as u128 * rhs.0 as u128) % n) as $type)
             }
         }
 
-        impl_ring_term_mul!($name);
-        impl_ring_mono_mul!($name);
+        $crate::impl_ring_term_mul!($name);
+        $crate::impl_ring_mono_mul!($name);
     };
 }
 
-static_finit_ring!(Z2(2:u32));
+/// [`static_finit_ring!`] with a prime modulus, which makes it a [`Field`]
+/// and a [`FiniteField`]. A modulus that is not prime fails to compile.
+///
+/// ```
+/// use grobner_basis::{polynomial::Polynomial, ring::Field, static_prime_field, variable::Var};
+///
+/// static_prime_field!(Z65537(65537:u32));
+///
+/// assert_eq!(Z65537(3) * Z65537(3).inv(), Z65537(1));
+/// let f: Polynomial<Z65537, Var> = "x^2+-3".parse().unwrap();
+/// assert_eq!((&f * &f).to_string(), "x⁴ + 65531x² + 9");
+/// ```
+///
+/// ```compile_fail
+/// grobner_basis::static_prime_field!(Z4(4:u32));
+/// ```
+#[macro_export]
+macro_rules! static_prime_field {
+    ( $name:ident($mod:literal:$type:tt )) => {
+        $crate::static_finit_ring!($name($mod:$type));
+
+        const _: () = assert!($crate::ring::is_prime($mod as u64), "The modulus must be prime");
+
+        impl $crate::ring::Field for $name {
+            // Fermat's little theorem, the modulus is prime.
+            fn inv(self) -> Self {
+                assert!(!$crate::num::Zero::is_zero(&self), "Division by zero");
+
+                let mut result = <Self as $crate::num::One>::one();
+                let mut base = self;
+                let mut exp = <Self as $crate::ring::Mod<$type>>::N - 2;
+                while exp > 0 {
+                    if exp % 2 == 1 {
+                        result = result * base;
+                    }
+                    base = base * base;
+                    exp /= 2;
+                }
+                result
+            }
+        }
+
+        impl $crate::ring::FiniteField for $name {
+            fn characteristic() -> u64 {
+                <Self as $crate::ring::Mod<$type>>::N as u64
+            }
+
+            fn from_u64(value: u64) -> Self {
+                Self((value % <Self as $crate::ring::FiniteField>::characteristic()) as $type)
+            }
+
+            fn to_u64(self) -> u64 {
+                self.0 as u64
+            }
+        }
+
+        impl std::ops::Div<$name> for $name {
+            type Output = $name;
+
+            #[inline]
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, rhs: $name) -> Self::Output {
+                self * $crate::ring::Field::inv(rhs)
+            }
+        }
+
+        // In a field every division is exact, so the remainder is always zero.
+        impl std::ops::Rem<$name> for $name {
+            type Output = $name;
+
+            #[inline]
+            fn rem(self, _rhs: $name) -> Self::Output {
+                <Self as $crate::num::Zero>::zero()
+            }
+        }
+
+    };
+}
+
+static_prime_field!(Z2(2:u32));
+
+thread_local! {
+    static MODULUS: Cell<u64> = const { Cell::new(2) };
+}
+
+/// Residues modulo a prime chosen at runtime, for the algorithms that need
+/// primes without a bound known in advance.
+///
+/// The modulus is set for the current thread by [`Zp::with_modulus`], and
+/// elements taken modulo different primes must not be mixed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Zp(u64);
+
+/// Restores the previous modulus when dropped, also on unwinding.
+struct ModulusGuard(u64);
+
+impl Drop for ModulusGuard {
+    fn drop(&mut self) {
+        MODULUS.set(self.0);
+    }
+}
+
+impl Zp {
+    /// Run `f` with arithmetic modulo the prime `p < 2^63`.
+    pub(crate) fn with_modulus<T>(p: u64, f: impl FnOnce() -> T) -> T {
+        assert!(p < 1 << 63, "The modulus must be below 2^63");
+        let _guard = ModulusGuard(MODULUS.replace(p));
+        f()
+    }
+
+    fn modulus() -> u64 {
+        MODULUS.get()
+    }
+}
+
+impl Display for Zp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Zero for Zp {
+    fn zero() -> Self {
+        Self(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for Zp {
+    fn one() -> Self {
+        Self(1)
+    }
+
+    fn is_one(&self) -> bool {
+        self.0 == 1
+    }
+}
+
+impl Add<Zp> for Zp {
+    type Output = Zp;
+
+    #[inline]
+    fn add(self, rhs: Zp) -> Self::Output {
+        Self((self.0 + rhs.0) % Self::modulus())
+    }
+}
+
+impl Neg for Zp {
+    type Output = Zp;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self((Self::modulus() - self.0) % Self::modulus())
+    }
+}
+
+impl Sub<Zp> for Zp {
+    type Output = Zp;
+
+    #[inline]
+    fn sub(self, rhs: Zp) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl Mul<Zp> for Zp {
+    type Output = Zp;
+
+    #[inline]
+    fn mul(self, rhs: Zp) -> Self::Output {
+        Self(mul_mod(self.0, rhs.0, Self::modulus()))
+    }
+}
+
+impl Div<Zp> for Zp {
+    type Output = Zp;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Zp) -> Self::Output {
+        self * rhs.inv()
+    }
+}
+
+// In a field every division is exact, so the remainder is always zero.
+impl Rem<Zp> for Zp {
+    type Output = Zp;
+
+    #[inline]
+    fn rem(self, _rhs: Zp) -> Self::Output {
+        Self::zero()
+    }
+}
+
+impl Field for Zp {
+    // Fermat's little theorem, the modulus is prime.
+    fn inv(self) -> Self {
+        assert!(!self.is_zero(), "Division by zero");
+        Self(pow_mod(self.0, Self::modulus() - 2, Self::modulus()))
+    }
+}
+
+impl FiniteField for Zp {
+    fn characteristic() -> u64 {
+        Self::modulus()
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Self(value % Self::modulus())
+    }
+
+    fn to_u64(self) -> u64 {
+        self.0
+    }
+}
+
+impl_ring_term_mul!(Zp);
+impl_ring_mono_mul!(Zp);
+
+const fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+const fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp /= 2;
+    }
+    result
+}
+
+/// Whether `n` is prime, by the Miller-Rabin test with the first twelve
+/// primes as bases, which is deterministic for all 64-bit integers. It is a
+/// `const fn` so that [`static_prime_field!`] can check its modulus.
+pub const fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < BASES.len() {
+        if n.is_multiple_of(BASES[i]) {
+            return n == BASES[i];
+        }
+        i += 1;
+    }
+
+    let (mut d, mut s) = (n - 1, 0);
+    while d.is_multiple_of(2) {
+        d /= 2;
+        s += 1;
+    }
+    let mut i = 0;
+    while i < BASES.len() {
+        let mut x = pow_mod(BASES[i], d, n);
+        let mut witness = x != 1 && x != n - 1;
+        let mut r = 1;
+        while witness && r < s {
+            x = mul_mod(x, x, n);
+            witness = x != n - 1;
+            r += 1;
+        }
+        if witness {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// The smallest prime larger than `n`.
+pub(crate) fn next_prime(n: u64) -> u64 {
+    (n + 1..).find(|&k| is_prime(k)).unwrap()
+}
+
+const RATIONAL_OVERFLOW: &str = "Rational arithmetic overflow, the coefficients do not fit in i64";
+
+/// Rational numbers with machine-sized numerator and denominator, as a field.
+///
+/// This is a small-coefficient type: arithmetic that leaves `i64` panics with
+/// an overflow message, which Buchberger's algorithm can reach on small
+/// inputs since its intermediate coefficients grow quickly. It is meant for
+/// coefficients known to stay small, such as the inputs of
+/// [`groebner_over_q`](crate::modular::groebner_over_q), whose results have
+/// arbitrary precision.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rational(Ratio<i64>);
+
+impl Rational {
+    pub fn new(numer: i64, denom: i64) -> Self {
+        Self(Ratio::new(numer, denom))
+    }
+
+    pub fn numer(&self) -> i64 {
+        *self.0.numer()
+    }
+
+    pub fn denom(&self) -> i64 {
+        *self.0.denom()
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Self(Ratio::from_integer(value))
+    }
+}
+
+impl From<Ratio<i64>> for Rational {
+    fn from(value: Ratio<i64>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Rational> for Ratio<i64> {
+    fn from(value: Rational) -> Self {
+        value.0
+    }
+}
+
+impl ToPrimitive for Rational {
+    fn to_i64(&self) -> Option<i64> {
+        self.0.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.0.to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.0.to_f64()
+    }
+}
+
+impl FromStr for Rational {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<Ratio<i64>>()
+            .map(Self)
+            .or(Err("Invalid rational value".to_owned()))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Zero for Rational {
+    fn zero() -> Self {
+        Self(Ratio::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl One for Rational {
+    fn one() -> Self {
+        Self(Ratio::one())
+    }
+
+    fn is_one(&self) -> bool {
+        self.0.is_one()
+    }
+}
+
+impl Add<Rational> for Rational {
+    type Output = Rational;
+
+    #[inline]
+    fn add(self, rhs: Rational) -> Self::Output {
+        Self(self.0.checked_add(&rhs.0).expect(RATIONAL_OVERFLOW))
+    }
+}
+
+impl Sub<Rational> for Rational {
+    type Output = Rational;
+
+    #[inline]
+    fn sub(self, rhs: Rational) -> Self::Output {
+        Self(self.0.checked_sub(&rhs.0).expect(RATIONAL_OVERFLOW))
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        let numer = self.numer().checked_neg().expect(RATIONAL_OVERFLOW);
+        Self(Ratio::new_raw(numer, self.denom()))
+    }
+}
+
+impl Mul<Rational> for Rational {
+    type Output = Rational;
+
+    #[inline]
+    fn mul(self, rhs: Rational) -> Self::Output {
+        Self(self.0.checked_mul(&rhs.0).expect(RATIONAL_OVERFLOW))
+    }
+}
+
+impl Div<Rational> for Rational {
+    type Output = Rational;
+
+    #[inline]
+    fn div(self, rhs: Rational) -> Self::Output {
+        assert!(!rhs.is_zero(), "Division by zero");
+        Self(self.0.checked_div(&rhs.0).expect(RATIONAL_OVERFLOW))
+    }
+}
+
+// In a field every division is exact, so the remainder is always zero.
+impl Rem<Rational> for Rational {
+    type Output = Rational;
+
+    #[inline]
+    fn rem(self, _rhs: Rational) -> Self::Output {
+        Self::zero()
+    }
+}
+
+impl Field for Rational {
+    fn inv(self) -> Self {
+        assert!(!self.is_zero(), "Division by zero");
+        Self(self.0.recip())
+    }
+}
+
+impl_ring_term_mul!(Rational);
+impl_ring_mono_mul!(Rational);
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    static_finit_ring!(Z5(5:u32));
+    static_prime_field!(Z5(5:u32));
+    static_prime_field!(Z4294967291(4294967291:u32));
+    static_finit_ring!(Z4(4:u32));
 
     #[test]
     fn finit_ring() {
@@ -227,5 +802,71 @@ mod tests {
         assert_eq!(*(Z5(4) * Z5(3)), 2);
         assert_eq!(*(Z5(2) * Z5(3)), 1);
         assert_eq!(*(Z5(2) * Z5(2)), 4);
+
+        assert!((-Z5(0)).is_zero());
+        assert_eq!(Z5(2).inv(), Z5(3));
+        assert_eq!(*(Z5(4) / Z5(2)), 2);
+        assert_eq!(*(Z5(1) / Z5(3)), 2);
+        assert!((Z5(3) % Z5(4)).is_zero());
+
+        assert_eq!(Z5::characteristic(), 5);
+        assert_eq!(Z5::from_u64(13), Z5(3));
+        assert_eq!(Z5(4).to_u64(), 4);
+
+        assert_eq!("-2".parse::<Z5>(), Ok(Z5(3)));
+        assert!("7".parse::<Z5>().is_err());
+    }
+
+    #[test]
+    fn composite_and_large_moduli() {
+        // Z4 is a ring with zero divisors, not a field
+        assert_eq!(Z4(2) * Z4(2), Z4::zero());
+        assert_eq!(Z4(3) * Z4(3), Z4::one());
+        assert_eq!(Z4(3) + Z4(3) - Z4(1), Z4(1));
+        assert!(!is_prime(4) && is_prime(4294967291));
+
+        // products of residues overflow u32
+        let a = Z4294967291(4294967290);
+        assert_eq!(a * a, Z4294967291::one());
+        assert_eq!(a + a, Z4294967291(4294967289));
+        assert_eq!(Z4294967291(3).inv() * Z4294967291(3), Z4294967291::one());
+    }
+
+    #[test]
+    fn runtime_prime_field() {
+        assert!(is_prime(2147483647) && !is_prime(2147483649));
+        assert_eq!(next_prime(1 << 62), (1 << 62) + 135);
+
+        let p = next_prime(1 << 62);
+        Zp::with_modulus(p, || {
+            let a = Zp::from_u64(p - 1);
+            assert_eq!(a * a, Zp::one());
+            assert_eq!(a + Zp::from_u64(2), Zp::one());
+            assert_eq!(Zp::from_u64(3).inv() * Zp::from_u64(3), Zp::one());
+            assert!((-Zp::zero()).is_zero());
+            Zp::with_modulus(7, || {
+                assert_eq!(Zp::from_u64(3) * Zp::from_u64(5), Zp::one())
+            });
+            assert_eq!(Zp::characteristic(), p);
+        });
+    }
+
+    #[test]
+    fn rational() {
+        let half = Rational::new(1, 2);
+        assert_eq!(half + Rational::new(1, 3), Rational::new(5, 6));
+        assert_eq!(half * Rational::from(4), Rational::from(2));
+        assert_eq!(Rational::new(2, -4), -half);
+        assert_eq!(Rational::new(3, 4).inv(), Rational::new(4, 3));
+        assert!((Rational::from(3) % half).is_zero());
+        assert_eq!("-3/6".parse::<Rational>(), Ok(-half));
+        assert_eq!(Rational::new(-6, 4).to_string(), "-3/2");
+        assert_eq!(Rational::new(-6, 4).to_f64(), Some(-1.5));
+    }
+
+    #[test]
+    #[should_panic(expected = "Rational arithmetic overflow")]
+    fn rational_overflow() {
+        let _ = Rational::new(i64::MAX, 3) * Rational::new(2, 5);
     }
 }
@@ -0,0 +1,151 @@
+use num::Zero;
+
+use crate::{
+    order::Lex,
+    polynomial::Polynomial,
+    ring::{FiniteField, Ring},
+    univariate::UnivariatePolynomial,
+    variable::Variable,
+    zero_dim::is_zero_dimensional,
+};
+
+/// Substitute the values of `point` in `f`, giving a polynomial in `var`, or
+/// `None` when `f` involves another variable without a value.
+fn specialize<R: Ring, V: Variable>(
+    f: &Polynomial<R, V, Lex>,
+    point: &[(V, R)],
+    var: V,
+) -> Option<UnivariatePolynomial<R>> {
+    let specialized = f.partial_eval(&|v| point.iter().find(|&&(w, _)| w == v).map(|&(_, x)| x));
+    UnivariatePolynomial::from_polynomial(&specialized, var)
+}
+
+/// All the points of a finite prime field where a [`Lex`] Gröbner basis of a
+/// zero-dimensional ideal vanishes, with the coordinates of `variables` in
+/// the order given.
+///
+/// The variables are assigned from the smallest for [`Lex`] to the largest, so
+/// the univariate eliminant comes first. For the next variable, the elements
+/// of `basis` involving only assigned variables are specialized at the
+/// partial point and the roots of their gcd extend it. Since the ideal is
+/// zero-dimensional, the basis has an element with a pure power of the next
+/// variable as lead term, so the gcd is never zero.
+///
+/// Panics when the ideal is not zero-dimensional in `variables`, or when
+/// `basis` involves other variables.
+pub fn solve_finite_field<R: FiniteField, V: Variable>(
+    basis: &[Polynomial<R, V, Lex>],
+    variables: &[V],
+) -> Vec<Vec<R>> {
+    assert!(
+        basis.iter().flat_map(|f| f.iter()).all(|m| m
+            .term
+            .exps
+            .iter()
+            .all(|(v, _)| variables.contains(v))),
+        "The basis must only involve the given variables"
+    );
+    assert!(
+        is_zero_dimensional(basis, variables),
+        "The ideal must be zero-dimensional"
+    );
+
+    // a nonzero constant in the basis means there are no solutions
+    if basis
+        .iter()
+        .any(|f| f.monomials.len() == 1 && f.monomials.first_key_value().unwrap().0.exps.is_empty())
+    {
+        return vec![];
+    }
+
+    let mut order = variables.to_vec();
+    order.sort();
+    order.dedup();
+
+    let mut partial: Vec<Vec<(V, R)>> = vec![vec![]];
+    for &var in order.iter().rev() {
+        partial = partial
+            .into_iter()
+            .flat_map(|point| {
+                let constraint = basis
+                    .iter()
+                    .filter_map(|f| specialize(f, &point, var))
+                    .fold(UnivariatePolynomial::zero(), |acc, g| acc.gcd(&g));
+                constraint.roots().into_iter().map(move |x| {
+                    let mut point = point.clone();
+                    point.push((var, x));
+                    point
+                })
+            })
+            .collect();
+    }
+
+    partial
+        .into_iter()
+        .map(|point| {
+            variables
+                .iter()
+                .map(|v| point.iter().find(|&&(w, _)| w == *v).unwrap().1)
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{polynomial::buchberger, static_prime_field, var, variable::Var};
+
+    static_prime_field!(Z7(7:u32));
+    static_prime_field!(Z65537(65537:u32));
+
+    #[test]
+    fn test_solve_finite_field() {
+        let generators: Vec<Polynomial<Z7, Var, Lex>> = vec![
+            Polynomial::from_str("x^2+y^2+-5").unwrap(),
+            Polynomial::from_str("xy+-2").unwrap(),
+        ];
+        let mut solutions = solve_finite_field(&buchberger(&generators), &[var!(x), var!(y)]);
+        solutions.sort_by_key(|point| point.iter().map(|x| **x).collect::<Vec<_>>());
+
+        // x^2 + y^2 = 5 and xy = 2 over Z7
+        let expected: Vec<Vec<Z7>> = [(1, 2), (2, 1), (5, 6), (6, 5)]
+            .iter()
+            .map(|&(x, y)| vec![Z7(x), Z7(y)])
+            .collect();
+        assert_eq!(solutions, expected);
+
+        let inconsistent: Vec<Polynomial<Z7, Var, Lex>> = vec![
+            Polynomial::from_str("x^2+-3").unwrap(),
+            Polynomial::from_str("y+-1").unwrap(),
+        ];
+        assert!(solve_finite_field(&buchberger(&inconsistent), &[var!(x), var!(y)]).is_empty());
+    }
+
+    #[test]
+    fn test_solve_finite_field_large() {
+        // x^2 = 2 and x y = 3 over Z65537, where 2 is a square since p = 1 mod 8,
+        // with the coordinates in the requested order
+        let generators: Vec<Polynomial<Z65537, Var, Lex>> = vec![
+            Polynomial::from_str("x^2+-2").unwrap(),
+            Polynomial::from_str("xy+-3").unwrap(),
+        ];
+        let solutions = solve_finite_field(&buchberger(&generators), &[var!(y), var!(x)]);
+        assert_eq!(solutions.len(), 2);
+        for point in solutions {
+            let (y, x) = (point[0], point[1]);
+            assert_eq!(x * x, Z65537(2));
+            assert_eq!(x * y, Z65537(3));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "The ideal must be zero-dimensional")]
+    fn test_solve_finite_field_positive_dimension() {
+        // y is free on the line x = y
+        let line: Vec<Polynomial<Z7, Var, Lex>> = vec![Polynomial::from_str("x+-y").unwrap()];
+        solve_finite_field(&line, &[var!(x), var!(y)]);
+    }
+}
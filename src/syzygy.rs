@@ -0,0 +1,117 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    lift::{spair, sub_quotients, tracked_buchberger},
+    module::ModuleElement,
+    order::{Order, OrderedTerm},
+    polynomial::{normal_form_quotients, Polynomial},
+    ring::Field,
+    variable::Variable,
+};
+
+/// Generators of the module of syzygies of `generators`, all the vectors
+/// `(h_1, ..., h_s)` with `sum(h_i * f_i) = 0`.
+///
+/// By Schreyer's theorem the reductions to zero of the S-polynomials of a
+/// Gröbner basis generate its syzygies. They are carried back to the
+/// generators through the representations of the basis, and completed with
+/// the reductions of the generators by the basis.
+pub fn syzygies<R: Field, V: Variable, O: Order>(
+    generators: &[Polynomial<R, V, O>],
+) -> Vec<ModuleElement<R, V, O>> {
+    let rank = generators.len();
+    let (basis, reprs) = tracked_buchberger(generators);
+
+    let pair_syzygies = (0..basis.len())
+        .flat_map(|j| (0..j).map(move |i| (i, j)))
+        .map(|(i, j)| {
+            let (s, repr) = spair(&basis, &reprs, i, j);
+            let (quotients, _) = normal_form_quotients(&s, &basis);
+            sub_quotients(repr, &quotients, &reprs)
+        });
+    let generator_syzygies = generators.iter().enumerate().map(|(i, f)| {
+        let (quotients, _) = normal_form_quotients(f, &basis);
+        sub_quotients(ModuleElement::unit(rank, i), &quotients, &reprs)
+    });
+
+    // duplicates have the same terms, so only those are compared in full
+    let mut supports: BTreeMap<Vec<Vec<OrderedTerm<V, O>>>, Vec<usize>> = Default::default();
+    let mut result: Vec<ModuleElement<R, V, O>> = Default::default();
+    for syzygy in pair_syzygies.chain(generator_syzygies) {
+        if syzygy.is_zero() {
+            continue;
+        }
+        let support = syzygy
+            .components()
+            .iter()
+            .map(|h| h.monomials.keys().cloned().collect())
+            .collect();
+        let same = supports.entry(support).or_default();
+        if same.iter().all(|&k| result[k] != syzygy) {
+            same.push(result.len());
+            result.push(syzygy);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use num::Zero;
+
+    use super::*;
+    use crate::{
+        module::{module_buchberger, module_normal_form},
+        order::TermOverPosition,
+        ring::Rational,
+        variable::Var,
+    };
+
+    #[test]
+    fn test_syzygies_koszul() {
+        let generators: Vec<Polynomial<Rational, Var>> = vec![
+            Polynomial::from_str("x").unwrap(),
+            Polynomial::from_str("y").unwrap(),
+        ];
+        assert_eq!(
+            syzygies(&generators),
+            vec![ModuleElement::new(vec![
+                Polynomial::from_str("y").unwrap(),
+                Polynomial::from_str("-1x").unwrap()
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_syzygies_twisted_cubic() {
+        // the 2x2 minors of [[w, x, y], [x, y, z]], whose rows give the two
+        // linear syzygies that generate all the others
+        let generators: Vec<Polynomial<Rational, Var>> = vec![
+            Polynomial::from_str("wy+-x^2").unwrap(),
+            Polynomial::from_str("xz+-y^2").unwrap(),
+            Polynomial::from_str("wz+-xy").unwrap(),
+        ];
+        let known: Vec<ModuleElement<Rational, Var>> = [["y", "w", "-1x"], ["z", "x", "-1y"]]
+            .iter()
+            .map(|s| {
+                ModuleElement::new(s.iter().map(|f| Polynomial::from_str(f).unwrap()).collect())
+            })
+            .collect();
+
+        let syzygies = syzygies(&generators);
+        assert!(syzygies.iter().all(|s| s.dot(&generators).is_zero()));
+
+        // both sets generate the same submodule
+        let contains = |basis: &[ModuleElement<Rational, Var>],
+                        elements: &[ModuleElement<Rational, Var>]| {
+            let basis = module_buchberger::<_, _, _, TermOverPosition>(basis);
+            elements
+                .iter()
+                .all(|s| module_normal_form::<_, _, _, TermOverPosition>(s, &basis).is_zero())
+        };
+        assert!(contains(&syzygies, &known));
+        assert!(contains(&known, &syzygies));
+    }
+}
@@ -48,7 +48,7 @@ impl<'a, V: Variable> FromIterator<&'a (V, usize)> for Term<V> {
 
 impl<V: Variable> Degree for Term<V> {
     fn deg(&self) -> usize {
-        self.exps.iter().map(|&(_, e)| e).product()
+        self.exps.iter().map(|&(_, e)| e).sum()
     }
 }
 
@@ -71,6 +71,14 @@ pub(crate) fn div_term_term<V: Variable>(left: &Term<V>, right: &Term<V>) -> Opt
         .ok()
 }
 
+#[inline]
+pub(crate) fn pow_term<V: Variable>(term: &Term<V>, exp: u32) -> Term<V> {
+    term.exps
+        .iter()
+        .map(|&(var, e)| (var, e * exp as usize))
+        .collect()
+}
+
 pub fn lcm<V: Variable>(left: &Term<V>, right: &Term<V>) -> Term<V> {
     left.exps
         .iter()
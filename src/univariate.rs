@@ -0,0 +1,560 @@
+use num::{One, Zero};
+
+use crate::{
+    order::Order,
+    polynomial::Polynomial,
+    ring::{mul_by_integer, Field, FiniteField, Ring},
+    term::Term,
+    variable::Variable,
+};
+
+/// Dense polynomial in a single variable, with coefficients stored from the
+/// constant term up and no trailing zeros.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnivariatePolynomial<R: Ring> {
+    coeffs: Vec<R>,
+}
+
+impl<R: Ring> UnivariatePolynomial<R> {
+    pub fn new(mut coeffs: Vec<R>) -> Self {
+        while coeffs.last().is_some_and(|c| c.is_zero()) {
+            coeffs.pop();
+        }
+        Self { coeffs }
+    }
+
+    pub fn constant(c: R) -> Self {
+        Self::new(vec![c])
+    }
+
+    /// The polynomial `x`.
+    pub fn x() -> Self {
+        Self::new(vec![R::zero(), R::one()])
+    }
+
+    pub fn coeffs(&self) -> &[R] {
+        &self.coeffs
+    }
+
+    /// Degree of the polynomial, `None` for zero.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    pub fn lead_coeff(&self) -> R {
+        self.coeffs.last().copied().unwrap_or(R::zero())
+    }
+
+    /// Value at `x` with Horner's rule.
+    pub fn eval(&self, x: R) -> R {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(R::zero(), |acc, &c| acc * x + c)
+    }
+
+    pub fn derivative(&self) -> Self {
+        Self::new(
+            self.coeffs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, &c)| mul_by_integer(c, i))
+                .collect(),
+        )
+    }
+
+    pub fn scale(&self, c: R) -> Self {
+        Self::new(self.coeffs.iter().map(|&a| a * c).collect())
+    }
+
+    /// Convert `f`, or `None` when it involves another variable than `var`.
+    pub fn from_polynomial<V: Variable, O: Order>(f: &Polynomial<R, V, O>, var: V) -> Option<Self> {
+        let mut coeffs: Vec<R> = Default::default();
+        for m in f.iter() {
+            let exp = match m.term.exps[..] {
+                [] => 0,
+                [(v, exp)] if v == var => exp,
+                _ => return None,
+            };
+            if coeffs.len() <= exp {
+                coeffs.resize(exp + 1, R::zero());
+            }
+            coeffs[exp] = m.coeff;
+        }
+        Some(Self::new(coeffs))
+    }
+
+    pub fn to_polynomial<V: Variable, O: Order>(&self, var: V) -> Polynomial<R, V, O> {
+        self.coeffs
+            .iter()
+            .enumerate()
+            .map(|(exp, &c)| Term::from_iter([(var, exp)]) * c)
+            .collect()
+    }
+}
+
+impl<R: Ring> Zero for UnivariatePolynomial<R> {
+    fn zero() -> Self {
+        Self { coeffs: vec![] }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+}
+
+impl<R: Ring> One for UnivariatePolynomial<R> {
+    fn one() -> Self {
+        Self::constant(R::one())
+    }
+}
+
+#[inline]
+pub(crate) fn add_uni_uni<R: Ring>(
+    left: &UnivariatePolynomial<R>,
+    right: &UnivariatePolynomial<R>,
+) -> UnivariatePolynomial<R> {
+    let len = left.coeffs.len().max(right.coeffs.len());
+    UnivariatePolynomial::new(
+        (0..len)
+            .map(|i| {
+                let a = left.coeffs.get(i).copied().unwrap_or(R::zero());
+                let b = right.coeffs.get(i).copied().unwrap_or(R::zero());
+                a + b
+            })
+            .collect(),
+    )
+}
+
+#[inline]
+pub(crate) fn neg_uni<R: Ring>(value: &UnivariatePolynomial<R>) -> UnivariatePolynomial<R> {
+    UnivariatePolynomial {
+        coeffs: value.coeffs.iter().map(|&c| -c).collect(),
+    }
+}
+
+#[inline]
+pub(crate) fn sub_uni_uni<R: Ring>(
+    left: &UnivariatePolynomial<R>,
+    right: &UnivariatePolynomial<R>,
+) -> UnivariatePolynomial<R> {
+    add_uni_uni(left, &neg_uni(right))
+}
+
+#[inline]
+pub(crate) fn mul_uni_uni<R: Ring>(
+    left: &UnivariatePolynomial<R>,
+    right: &UnivariatePolynomial<R>,
+) -> UnivariatePolynomial<R> {
+    if left.is_zero() || right.is_zero() {
+        return UnivariatePolynomial::zero();
+    }
+
+    let mut coeffs = vec![R::zero(); left.coeffs.len() + right.coeffs.len() - 1];
+    for (i, &a) in left.coeffs.iter().enumerate() {
+        if a.is_zero() {
+            continue;
+        }
+        for (j, &b) in right.coeffs.iter().enumerate() {
+            coeffs[i + j] = coeffs[i + j] + a * b;
+        }
+    }
+    UnivariatePolynomial::new(coeffs)
+}
+
+impl<R: Field> UnivariatePolynomial<R> {
+    /// Euclidean division, returns the quotient and the remainder.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let d = divisor.degree().expect("Division by zero");
+        let inv = divisor.lead_coeff().inv();
+
+        let mut remainder = self.coeffs.clone();
+        let mut quotient = vec![R::zero(); self.coeffs.len().saturating_sub(d)];
+        for i in (d..remainder.len()).rev() {
+            let c = remainder[i] * inv;
+            if c.is_zero() {
+                continue;
+            }
+            quotient[i - d] = c;
+            for (j, &b) in divisor.coeffs.iter().enumerate() {
+                remainder[i - d + j] = remainder[i - d + j] - c * b;
+            }
+        }
+        remainder.truncate(d);
+
+        (Self::new(quotient), Self::new(remainder))
+    }
+
+    pub fn monic(&self) -> Self {
+        if self.is_zero() {
+            return self.clone();
+        }
+        self.scale(self.lead_coeff().inv())
+    }
+
+    /// Monic greatest common divisor, zero when both are zero.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let (_, r) = a.div_rem(&b);
+            (a, b) = (b, r);
+        }
+        a.monic()
+    }
+
+    /// Monic `g = gcd(self, other)` with Bézout coefficients `s` and `t` such
+    /// that `g = s * self + t * other`.
+    pub fn extended_gcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (mut r0, mut r1) = (self.clone(), other.clone());
+        let (mut s0, mut s1) = (Self::one(), Self::zero());
+        let (mut t0, mut t1) = (Self::zero(), Self::one());
+
+        while !r1.is_zero() {
+            let (q, r) = r0.div_rem(&r1);
+            (r0, r1) = (r1, r);
+            (s0, s1) = (s1.clone(), &s0 - &q * &s1);
+            (t0, t1) = (t1.clone(), &t0 - &q * &t1);
+        }
+
+        if r0.is_zero() {
+            return (r0, s0, t0);
+        }
+        let inv = r0.lead_coeff().inv();
+        (r0.scale(inv), s0.scale(inv), t0.scale(inv))
+    }
+
+    /// `self^exp` modulo `modulus`, by repeated squaring.
+    pub fn pow_mod(&self, mut exp: u64, modulus: &Self) -> Self {
+        let mut result = Self::one().div_rem(modulus).1;
+        let mut base = self.div_rem(modulus).1;
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result = (&result * &base).div_rem(modulus).1;
+            }
+            base = (&base * &base).div_rem(modulus).1;
+            exp /= 2;
+        }
+        result
+    }
+}
+
+impl<R: FiniteField> UnivariatePolynomial<R> {
+    /// `g` such that `self = g^p`, valid when only powers of `x^p` appear,
+    /// since every element of a prime field is its own `p`-th power.
+    fn pth_root(&self) -> Self {
+        let p = R::characteristic() as usize;
+        Self::new(self.coeffs.iter().step_by(p).copied().collect())
+    }
+
+    /// Monic square-free factors `(g, m)` with `self = c * prod(g^m)` and the
+    /// `g` pairwise coprime, following Musser's algorithm for characteristic
+    /// `p`.
+    pub fn square_free_factorization(&self) -> Vec<(Self, usize)> {
+        let p = R::characteristic() as usize;
+        let f = self.monic();
+        if f.degree().unwrap_or(0) == 0 {
+            return vec![];
+        }
+
+        let derivative = f.derivative();
+        if derivative.is_zero() {
+            return f
+                .pth_root()
+                .square_free_factorization()
+                .into_iter()
+                .map(|(g, m)| (g, m * p))
+                .collect();
+        }
+
+        let mut factors: Vec<(Self, usize)> = Default::default();
+        let mut c = f.gcd(&derivative);
+        let mut w = f.div_rem(&c).0;
+        let mut i = 1;
+        while w.degree() > Some(0) {
+            let y = w.gcd(&c);
+            let factor = w.div_rem(&y).0;
+            if factor.degree() > Some(0) {
+                factors.push((factor, i));
+            }
+            c = c.div_rem(&y).0;
+            w = y;
+            i += 1;
+        }
+
+        // what is left only has factors of multiplicity divisible by p
+        if c.degree() > Some(0) {
+            factors.extend(
+                c.pth_root()
+                    .square_free_factorization()
+                    .into_iter()
+                    .map(|(g, m)| (g, m * p)),
+            );
+        }
+        factors
+    }
+
+    /// Split a monic product of distinct linear factors into its roots with
+    /// the Cantor–Zassenhaus algorithm.
+    fn split_linear(&self, roots: &mut Vec<R>) {
+        let p = R::characteristic();
+        match self.degree() {
+            None | Some(0) => return,
+            Some(1) => {
+                roots.push(-self.coeffs[0]);
+                return;
+            }
+            _ => {}
+        }
+
+        if p == 2 {
+            roots.extend(
+                [R::zero(), R::one()]
+                    .into_iter()
+                    .filter(|&r| self.eval(r).is_zero()),
+            );
+            return;
+        }
+
+        // Shifts are taken as 0, 1, 2, ... instead of random ones. Two roots
+        // r != s are separated as soon as r + a and s + a differ in being a
+        // square, which happens for some a.
+        for a in 0..p {
+            let shift = Self::x() + Self::constant(R::from_u64(a));
+            let h = self.gcd(&(shift.pow_mod((p - 1) / 2, self) - Self::one()));
+            if h.degree() > Some(0) && h.degree() < self.degree() {
+                h.split_linear(roots);
+                self.div_rem(&h).0.split_linear(roots);
+                return;
+            }
+        }
+    }
+
+    /// Products `(g, d)` of the irreducible factors of degree `d` of a monic
+    /// square-free polynomial, for the increasing degrees that occur.
+    pub fn distinct_degree_factorization(&self) -> Vec<(Self, usize)> {
+        let p = R::characteristic();
+        let x = Self::x();
+        let mut f = self.clone();
+        let mut h = x.clone();
+        let mut factors: Vec<(Self, usize)> = Default::default();
+
+        let mut d = 1;
+        while let Some(n) = f.degree().filter(|&n| n > 0) {
+            if 2 * d > n {
+                factors.push((f, n));
+                break;
+            }
+            // x^(p^d) - x is the product of the irreducibles of degree dividing d
+            h = h.pow_mod(p, &f);
+            let g = f.gcd(&(&h - &x));
+            if g.degree() > Some(0) {
+                f = f.div_rem(&g).0;
+                h = h.div_rem(&f).1;
+                factors.push((g, d));
+            }
+            d += 1;
+        }
+        factors
+    }
+
+    /// Split a monic product of distinct irreducible factors of degree `d`
+    /// with the Cantor–Zassenhaus algorithm.
+    fn equal_degree_factorization(&self, d: usize, factors: &mut Vec<Self>) {
+        let p = R::characteristic();
+        let n = self.degree().unwrap_or(0);
+        if n <= d {
+            if n > 0 {
+                factors.push(self.clone());
+            }
+            return;
+        }
+
+        // pseudo-random candidates, so that the factorization is deterministic
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        loop {
+            let a = Self::new(
+                (0..n)
+                    .map(|_| {
+                        state ^= state << 13;
+                        state ^= state >> 7;
+                        state ^= state << 17;
+                        R::from_u64(state % p)
+                    })
+                    .collect(),
+            );
+            if a.degree() < Some(1) {
+                continue;
+            }
+
+            let splitter = if p == 2 {
+                // the trace to the prime field is 0 or 1 modulo each factor
+                let mut power = a.clone();
+                let mut trace = a.clone();
+                for _ in 1..d {
+                    power = (&power * &power).div_rem(self).1;
+                    trace = &trace + &power;
+                }
+                trace
+            } else {
+                // a^((p^d - 1) / 2) is 1 or -1 modulo each factor not dividing a
+                let mut power = a.clone();
+                let mut norm = a.clone();
+                for _ in 1..d {
+                    power = power.pow_mod(p, self);
+                    norm = (&norm * &power).div_rem(self).1;
+                }
+                norm.pow_mod((p - 1) / 2, self) - Self::one()
+            };
+
+            let g = self.gcd(&splitter);
+            if g.degree() > Some(0) && g.degree() < Some(n) {
+                g.equal_degree_factorization(d, factors);
+                self.div_rem(&g).0.equal_degree_factorization(d, factors);
+                return;
+            }
+        }
+    }
+
+    /// Monic irreducible factors `(g, m)` with `self = c * prod(g^m)`, sorted
+    /// by degree and then by coefficients.
+    pub fn factor(&self) -> Vec<(Self, usize)> {
+        let mut factors: Vec<(Self, usize)> = Default::default();
+        for (g, m) in self.square_free_factorization() {
+            for (h, d) in g.distinct_degree_factorization() {
+                let mut irreducibles: Vec<Self> = Default::default();
+                h.equal_degree_factorization(d, &mut irreducibles);
+                factors.extend(irreducibles.into_iter().map(|q| (q, m)));
+            }
+        }
+        factors.sort_by_key(|(g, _)| {
+            (
+                g.coeffs.len(),
+                g.coeffs
+                    .iter()
+                    .rev()
+                    .map(|c| c.to_u64())
+                    .collect::<Vec<_>>(),
+            )
+        });
+        factors
+    }
+
+    /// Distinct roots in the field, in increasing order of representative.
+    pub fn roots(&self) -> Vec<R> {
+        assert!(!self.is_zero(), "The zero polynomial vanishes everywhere");
+
+        let f = self.monic();
+        let x = Self::x();
+        // gcd with x^p - x keeps one copy of every linear factor
+        let linear = f.gcd(&(x.pow_mod(R::characteristic(), &f) - &x));
+
+        let mut roots: Vec<R> = Default::default();
+        linear.split_linear(&mut roots);
+        roots.sort_by_key(|&r| r.to_u64());
+        roots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{order::Lex, static_prime_field, var, variable::Var};
+
+    static_prime_field!(Z2(2:u32));
+    static_prime_field!(Z7(7:u32));
+    static_prime_field!(Z101(101:u32));
+
+    fn poly<R: FiniteField>(coeffs: &[u32]) -> UnivariatePolynomial<R> {
+        UnivariatePolynomial::new(coeffs.iter().map(|&c| R::from_u64(c as u64)).collect())
+    }
+
+    #[test]
+    fn test_division_and_gcd() {
+        // (x + 1)(x + 2) and (x + 1)(x + 3) over Z7
+        let f: UnivariatePolynomial<Z7> = poly(&[2, 3, 1]);
+        let g: UnivariatePolynomial<Z7> = poly(&[3, 4, 1]);
+
+        let (q, r) = f.div_rem(&g);
+        assert_eq!(&(&q * &g) + &r, f);
+        assert_eq!(f.gcd(&g), poly(&[1, 1]));
+
+        let (d, s, t) = f.extended_gcd(&g);
+        assert_eq!(&(&s * &f) + &(&t * &g), d);
+        assert_eq!(f.eval(Z7(5)), Z7(0));
+        assert_eq!(f.derivative(), poly(&[3, 2]));
+
+        let converted: Polynomial<Z7, Var, Lex> = Polynomial::from_str("x^2+3x+2").unwrap();
+        assert_eq!(
+            UnivariatePolynomial::from_polynomial(&converted, var!(x)),
+            Some(f.clone())
+        );
+        assert_eq!(f.to_polynomial::<Var, Lex>(var!(x)), converted);
+        assert_eq!(
+            UnivariatePolynomial::from_polynomial(&converted, var!(y)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_square_free_factorization() {
+        // (x + 1)^2 (x^7 + 2) (x + 3) over Z7, where x^7 + 2 = (x + 2)^7
+        let f: UnivariatePolynomial<Z7> = &(&poly::<Z7>(&[1, 1]) * &poly(&[1, 1]))
+            * &(&poly::<Z7>(&[2, 0, 0, 0, 0, 0, 0, 1]) * &poly(&[3, 1]));
+
+        let factors = f.square_free_factorization();
+        assert_eq!(
+            factors,
+            vec![(poly(&[3, 1]), 1), (poly(&[1, 1]), 2), (poly(&[2, 1]), 7)]
+        );
+    }
+
+    #[test]
+    fn test_factor() {
+        // x^4 + x + 1 is irreducible over Z2, x^4 + x^2 + 1 = (x^2 + x + 1)^2
+        let f: UnivariatePolynomial<Z2> = poly(&[1, 1, 0, 0, 1]);
+        assert_eq!(f.factor(), vec![(f.clone(), 1)]);
+        let g: UnivariatePolynomial<Z2> = &f * &poly(&[1, 0, 1, 0, 1]);
+        assert_eq!(
+            g.factor(),
+            vec![(poly(&[1, 1, 1]), 2), (poly(&[1, 1, 0, 0, 1]), 1)]
+        );
+
+        // 3 (x^2 + 1)(x^2 + 2)^2 (x + 5) over Z7, with x^2 + 1 and x^2 + 2 irreducible
+        let h: UnivariatePolynomial<Z7> = [poly(&[1, 0, 1]), poly(&[2, 0, 1]), poly(&[2, 0, 1])]
+            .iter()
+            .fold(poly(&[15, 3]), |acc, g| &acc * g);
+        let quadratics = poly::<Z7>(&[1, 0, 1]) * poly(&[2, 0, 1]);
+        assert_eq!(
+            quadratics.distinct_degree_factorization(),
+            vec![(quadratics.clone(), 2)]
+        );
+        assert_eq!(
+            h.factor(),
+            vec![
+                (poly(&[5, 1]), 1),
+                (poly(&[1, 0, 1]), 1),
+                (poly(&[2, 0, 1]), 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_roots() {
+        // (x - 3)(x - 10)(x - 50)(x^2 + 1)
+        let f: UnivariatePolynomial<Z101> = [3, 10, 50]
+            .iter()
+            .map(|&r| poly::<Z101>(&[101 - r, 1]))
+            .fold(poly(&[1, 0, 1]), |acc, g| &acc * &g);
+        // x^2 + 1 = (x - 10)(x - 91) modulo 101
+        assert_eq!(f.roots(), vec![Z101(3), Z101(10), Z101(50), Z101(91)]);
+
+        let g: UnivariatePolynomial<Z7> = &poly::<Z7>(&[1, 0, 1]) * &poly(&[6, 1]);
+        assert_eq!(g.roots(), vec![Z7(1)]);
+
+        let h: UnivariatePolynomial<Z2> = poly(&[0, 1, 1]);
+        assert_eq!(h.roots(), vec![Z2(0), Z2(1)]);
+    }
+}
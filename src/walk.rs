@@ -0,0 +1,197 @@
+use std::{cmp::Ordering, ops::Rem};
+
+use num::{Integer, Zero};
+
+use crate::{
+    order::{Order, WeightOrder},
+    polynomial::{
+        buchberger, normal_form_quotients_by, reduced_basis, reduced_basis_by, Polynomial,
+    },
+    ring::Field,
+    term::Term,
+    variable::Variable,
+};
+
+type TermCmp<'a, V> = dyn Fn(&Term<V>, &Term<V>) -> Ordering + 'a;
+
+fn lead_by<R: Field, V: Variable, P: Order>(
+    f: &Polynomial<R, V, P>,
+    cmp: &TermCmp<V>,
+) -> Option<(Term<V>, R)> {
+    f.monomials
+        .iter()
+        .max_by(|(left, _), (right, _)| cmp(left, right))
+        .map(|(term, &coeff)| ((**term).clone(), coeff))
+}
+
+fn initial_form<R: Field, V: Variable, P: Order>(
+    f: &Polynomial<R, V, P>,
+    order: &WeightOrder<V, P>,
+) -> Polynomial<R, V, P> {
+    let top = f.iter().map(|m| order.weight(&m.term)).max();
+    f.iter()
+        .filter(|m| Some(order.weight(&m.term)) == top)
+        .collect()
+}
+
+/// Smallest `t` in `[0, 1)`, as a fraction, where the path from `current`
+/// to `target` leaves the Gröbner cone of `basis`.
+fn next_cone<R: Field, V: Variable, P: Order>(
+    basis: &[Polynomial<R, V, P>],
+    cmp: &TermCmp<V>,
+    current: &WeightOrder<V, P>,
+    target: &WeightOrder<V, P>,
+) -> Option<(i64, i64)> {
+    let mut next: Option<(i64, i64)> = None;
+
+    for g in basis.iter() {
+        let Some((lead, _)) = lead_by(g, cmp) else {
+            continue;
+        };
+        for m in g.iter() {
+            let current_diff = current.weight(&lead) - current.weight(&m.term);
+            let target_diff = target.weight(&lead) - target.weight(&m.term);
+            if target_diff >= 0 {
+                continue;
+            }
+
+            let t = (current_diff, current_diff - target_diff);
+            let closer = next.is_none_or(|(num, den)| {
+                (t.0 as i128) * (den as i128) < (num as i128) * (t.1 as i128)
+            });
+            if closer {
+                next = Some(t);
+            }
+        }
+    }
+
+    next
+}
+
+/// Convert a Gröbner basis for the order `O` into the reduced Gröbner basis
+/// for the order `P`, following the Gröbner walk of Collart, Kalkbrener and
+/// Mall.
+///
+/// The walk follows a straight path of weight vectors from `start_weights` to
+/// `target_weights`, given in the order of `variables`. The weights must be
+/// nonnegative and represent the orders: refining `start_weights` by `O` must
+/// give `O` back and refining `target_weights` by `P` must give `P`, e.g.
+/// `(1, 0, ..., 0)` for [`Lex`](crate::order::Lex) and `(1, ..., 1)` for
+/// [`GradLex`](crate::order::GradLex). Every time the path crosses a Gröbner
+/// cone, a basis of the initial ideal is computed with [`buchberger`] and
+/// lifted back to the ideal.
+pub fn groebner_walk<R, V: Variable, O: Order, P: Order>(
+    basis: &[Polynomial<R, V, O>],
+    variables: &[V],
+    start_weights: &[i64],
+    target_weights: &[i64],
+) -> Vec<Polynomial<R, V, P>>
+where
+    R: Field + Rem<R, Output = R>,
+{
+    assert!(
+        start_weights.iter().chain(target_weights).all(|&w| w >= 0),
+        "Weights must be nonnegative"
+    );
+
+    let start_order: WeightOrder<V, O> = WeightOrder::new(variables, start_weights);
+    let target_order: WeightOrder<V, P> = WeightOrder::new(variables, target_weights);
+    let mut current_order: WeightOrder<V, P> = WeightOrder::new(variables, start_weights);
+    let mut cmp: Box<TermCmp<V>> = Box::new(move |left, right| start_order.cmp(left, right));
+
+    let mut g: Vec<Polynomial<R, V, P>> = reduced_basis(basis)
+        .iter()
+        .map(|f| f.iter().collect())
+        .collect();
+
+    loop {
+        let step = next_cone(&g, &*cmp, &current_order, &target_order);
+        let weights: Vec<i64> = match step {
+            Some((num, den)) => {
+                let weights: Vec<i64> = current_order
+                    .weights()
+                    .iter()
+                    .zip(target_order.weights())
+                    .map(|(&u, t)| (den - num) * u + num * t)
+                    .collect();
+                let divisor = weights.iter().fold(0, |acc, w| acc.gcd(w)).max(1);
+                weights.iter().map(|w| w / divisor).collect()
+            }
+            None => target_order.weights(),
+        };
+        let mut sorted_variables = variables.to_vec();
+        sorted_variables.sort();
+        let next_order: WeightOrder<V, P> = WeightOrder::new(&sorted_variables, &weights);
+
+        // initial forms are homogeneous for the weights, so that the order
+        // refined by the weights coincides with P on them
+        let initial: Vec<Polynomial<R, V, P>> =
+            g.iter().map(|f| initial_form(f, &next_order)).collect();
+        let initial_basis = reduced_basis(&buchberger(&initial));
+
+        let lifted: Vec<Polynomial<R, V, P>> = initial_basis
+            .iter()
+            .map(|h| {
+                let (quotients, _) = normal_form_quotients_by(h, &initial, &|f| lead_by(f, &*cmp));
+                quotients
+                    .iter()
+                    .zip(g.iter())
+                    .fold(Polynomial::zero(), |acc, (q, f)| acc + q * f)
+            })
+            .collect();
+
+        let order = next_order.clone();
+        cmp = Box::new(move |left, right| order.cmp(left, right));
+        g = reduced_basis_by(&lifted, &|f| lead_by(f, &*cmp));
+        current_order = next_order;
+
+        if step.is_none() {
+            break;
+        }
+    }
+
+    reduced_basis(&g)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        order::{GradLex, Lex},
+        static_prime_field, var,
+        variable::Var,
+    };
+
+    static_prime_field!(Z101(101:u32));
+
+    #[test]
+    fn test_walk_grad_lex_to_lex() {
+        let generators: Vec<Polynomial<Z101, Var, GradLex>> = vec![
+            Polynomial::from_str("x^2+y^2+z^2+-1").unwrap(),
+            Polynomial::from_str("xy+-z").unwrap(),
+        ];
+        let variables = [var!(x), var!(y), var!(z)];
+        let grad_lex_basis = buchberger(&generators);
+
+        let lex_basis: Vec<Polynomial<Z101, Var, Lex>> =
+            groebner_walk(&grad_lex_basis, &variables, &[1, 1, 1], &[1, 0, 0]);
+
+        let lex_generators: Vec<Polynomial<Z101, Var, Lex>> =
+            generators.iter().map(|f| f.iter().collect()).collect();
+        assert_eq!(lex_basis, reduced_basis(&buchberger(&lex_generators)));
+
+        let generators: Vec<Polynomial<Z101, Var, GradLex>> = vec![
+            Polynomial::from_str("x^2+y+-3").unwrap(),
+            Polynomial::from_str("y^2+x+-1").unwrap(),
+            Polynomial::from_str("xyz+-2").unwrap(),
+        ];
+        let lex_basis: Vec<Polynomial<Z101, Var, Lex>> =
+            groebner_walk(&buchberger(&generators), &variables, &[1, 1, 1], &[1, 0, 0]);
+
+        let lex_generators: Vec<Polynomial<Z101, Var, Lex>> =
+            generators.iter().map(|f| f.iter().collect()).collect();
+        assert_eq!(lex_basis, reduced_basis(&buchberger(&lex_generators)));
+    }
+}
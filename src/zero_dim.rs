@@ -0,0 +1,202 @@
+use std::collections::BTreeMap;
+
+use num::Zero;
+
+use crate::{
+    matrix::Matrix,
+    order::{Order, OrderedTerm},
+    polynomial::{normal_form, HeadMonomial, Polynomial},
+    ring::{Field, Ring},
+    term::Term,
+    variable::Variable,
+};
+
+fn enumerate_standard<V: Variable>(
+    variables: &[V],
+    bounds: &[usize],
+    leads: &[Term<V>],
+    prefix: &mut Vec<(V, usize)>,
+    standard: &mut Vec<Term<V>>,
+) {
+    let i = prefix.len();
+    if i == variables.len() {
+        standard.push(prefix.iter().collect());
+        return;
+    }
+
+    for exp in 0..bounds[i] {
+        prefix.push((variables[i], exp));
+        let term: Term<V> = prefix.iter().collect();
+        // every multiple of a divisible term is divisible as well
+        if leads.iter().any(|lead| (&term / lead).is_some()) {
+            prefix.pop();
+            break;
+        }
+        enumerate_standard(variables, bounds, leads, prefix, standard);
+        prefix.pop();
+    }
+}
+
+/// Monomials of `variables` not divisible by any lead term of `basis`, in
+/// increasing order. They form a vector space basis of `k[x]/I` when `basis`
+/// is a Gröbner basis.
+///
+/// Returns `None` when the ideal is not zero-dimensional, that is when some
+/// variable has no pure power among the lead terms.
+pub fn standard_monomials<R: Ring, V: Variable, O: Order>(
+    basis: &[Polynomial<R, V, O>],
+    variables: &[V],
+) -> Option<Vec<Term<V>>> {
+    let leads: Vec<Term<V>> = basis
+        .iter()
+        .filter(|g| !g.is_zero())
+        .map(|g| g.lead_term())
+        .collect();
+
+    let mut variables = variables.to_vec();
+    variables.sort();
+    variables.dedup();
+
+    if leads.iter().any(|lead| lead.exps.is_empty()) {
+        return Some(vec![]);
+    }
+
+    let bounds = variables
+        .iter()
+        .map(|&var| {
+            leads
+                .iter()
+                .filter_map(|lead| match lead.exps[..] {
+                    [(v, exp)] if v == var => Some(exp),
+                    _ => None,
+                })
+                .min()
+        })
+        .collect::<Option<Vec<usize>>>()?;
+
+    let mut standard: Vec<Term<V>> = Default::default();
+    enumerate_standard(&variables, &bounds, &leads, &mut vec![], &mut standard);
+    standard.sort_by(O::cmp);
+    Some(standard)
+}
+
+pub fn is_zero_dimensional<R: Ring, V: Variable, O: Order>(
+    basis: &[Polynomial<R, V, O>],
+    variables: &[V],
+) -> bool {
+    standard_monomials(basis, variables).is_some()
+}
+
+/// Dimension of `k[x]/I` as a vector space, the number of solutions counted
+/// with multiplicity over the algebraic closure.
+pub fn quotient_dimension<R: Ring, V: Variable, O: Order>(
+    basis: &[Polynomial<R, V, O>],
+    variables: &[V],
+) -> Option<usize> {
+    standard_monomials(basis, variables).map(|standard| standard.len())
+}
+
+/// Coordinates of `f` in the basis of standard monomials, `f` must already be
+/// in normal form.
+pub(crate) fn coordinates<R: Ring, V: Variable, O: Order>(
+    f: &Polynomial<R, V, O>,
+    index: &BTreeMap<OrderedTerm<V, O>, usize>,
+) -> Vec<R> {
+    let mut coords = vec![R::zero(); index.len()];
+    for (term, &coeff) in f.monomials.iter() {
+        let i = *index
+            .get(term)
+            .expect("The normal form is not spanned by the standard monomials");
+        coords[i] = coeff;
+    }
+    coords
+}
+
+pub(crate) fn standard_index<V: Variable, O: Order>(
+    standard: &[Term<V>],
+) -> BTreeMap<OrderedTerm<V, O>, usize> {
+    standard
+        .iter()
+        .enumerate()
+        .map(|(i, term)| (term.clone().into(), i))
+        .collect()
+}
+
+/// Matrix of the multiplication by `multiplier` on `k[x]/I`, the column `j`
+/// holds the coordinates of the normal form of `multiplier * standard[j]`.
+pub fn multiplication_matrix<R: Field, V: Variable, O: Order>(
+    basis: &[Polynomial<R, V, O>],
+    multiplier: &Polynomial<R, V, O>,
+    standard: &[Term<V>],
+) -> Matrix<R> {
+    let index = standard_index::<V, O>(standard);
+    let mut matrix = Matrix::zeros(standard.len(), standard.len());
+
+    for (j, term) in standard.iter().enumerate() {
+        let product = multiplier * term;
+        let coords = coordinates(&normal_form(&product, basis), &index);
+        for (i, coeff) in coords.into_iter().enumerate() {
+            matrix[(i, j)] = coeff;
+        }
+    }
+    matrix
+}
+
+/// Matrices of the multiplication by each of `variables`, expressed in the
+/// basis returned by [`standard_monomials`], or `None` when the ideal is not
+/// zero-dimensional.
+pub fn multiplication_matrices<R: Field, V: Variable, O: Order>(
+    basis: &[Polynomial<R, V, O>],
+    variables: &[V],
+) -> Option<Vec<Matrix<R>>> {
+    let standard = standard_monomials(basis, variables)?;
+    let matrices = variables
+        .iter()
+        .map(|&var| {
+            let x: Polynomial<R, V, O> = [Term::from_iter([(var, 1)]) * R::one()]
+                .into_iter()
+                .collect();
+            multiplication_matrix(basis, &x, &standard)
+        })
+        .collect();
+    Some(matrices)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{order::GradLex, polynomial::buchberger, static_prime_field, var, variable::Var};
+
+    static_prime_field!(Z7(7:u32));
+
+    #[test]
+    fn test_multiplication_matrices() {
+        let generators: Vec<Polynomial<Z7, Var, GradLex>> = vec![
+            Polynomial::from_str("x^2+-y").unwrap(),
+            Polynomial::from_str("y^2+-1").unwrap(),
+        ];
+        let basis = buchberger(&generators);
+        let variables = [var!(x), var!(y)];
+
+        let standard = standard_monomials(&basis, &variables).unwrap();
+        let expected: Vec<Term<Var>> = ["", "y", "x", "xy"]
+            .iter()
+            .map(|s| Term::from_str(s).unwrap())
+            .collect();
+        assert_eq!(standard, expected);
+        assert_eq!(quotient_dimension(&basis, &variables), Some(4));
+
+        let matrices = multiplication_matrices(&basis, &variables).unwrap();
+        let (m_x, m_y) = (&matrices[0], &matrices[1]);
+
+        // x * x = y and x * xy = 1
+        assert_eq!(m_x.col(2), vec![Z7(0), Z7(1), Z7(0), Z7(0)]);
+        assert_eq!(m_x.col(3), vec![Z7(1), Z7(0), Z7(0), Z7(0)]);
+        assert_eq!(m_x * m_y, m_y * m_x);
+        assert_eq!(&(m_x * m_x) * &(m_x * m_x), Matrix::identity(4));
+
+        assert!(!is_zero_dimensional(&generators[..1], &variables));
+    }
+}